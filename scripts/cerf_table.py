@@ -0,0 +1,40 @@
+from mpmath import mp, mpc, erf, erfc, erfi, exp, sqrt, pi
+
+mp.dps = 50
+
+# z values away from the axes
+z_values = [
+    (0.5, 0.5), (1.0, -2.0), (-1.5, 0.75), (-0.3, -2.5),  # Moderate values
+    (3.0, 4.0), (-6.0, 2.0), (10.0, -10.0), (-25.0, 1.5),  # Larger values
+    (1e-3, 1e-3), (-2e-2, 5e-3), (0.25, -0.05), (-0.1, 0.3),  # Near the origin
+]
+
+# z values on and next to the axes
+axis_values = [
+    (0.5, 0.0), (-2.0, 0.0), (6.0, 0.0),
+    (0.0, 0.5), (0.0, -2.0), (0.0, 6.0),
+    (4e-3, -0.5), (1.5, 1e-4),
+]
+
+
+def cdawson(z):
+    return sqrt(pi) / 2 * exp(-z * z) * erfi(z)
+
+
+def cerfcx(z):
+    return exp(z * z) * erfc(z)
+
+
+functions = [
+    ("CERF", erf), ("CERFC", erfc), ("CERFI", erfi), ("CERFCX", cerfcx), ("CDAWSON", cdawson),
+]
+
+# Print in Rust code format
+for name, f in functions:
+    for suffix, values in [("", z_values), ("_AXIS", axis_values)]:
+        table = [(x, y, f(mpc(x, y))) for x, y in values]
+        print("const {}{}_TABLE: [(f64, f64, f64, f64); {}] = [".format(name, suffix, len(table)))
+        for x, y, w in table:
+            print("    ({:.16e}, {:.16e}, {:.16e}, {:.16e}),".format(x, y, float(w.real), float(w.imag)))
+        print("];")
+        print()
@@ -0,0 +1,43 @@
+from mpmath import mp, mpc, loggamma, gamma
+
+mp.dps = 50
+
+# ┌──────────────────────────────────────────────────────────┐
+#  cln_gamma
+# └──────────────────────────────────────────────────────────┘
+# z values to test
+cln_gamma_z_values = [
+    (1.0, 0.0), (2.0, 0.0), (0.5, 0.0), (1.1, 0.1), (2.1, -0.05),  # Near the zeros at 1 and 2
+    (0.5, 0.5), (3.0, 4.0), (5.5, -2.5),  # Moderate values
+    (-0.5, 0.0), (-2.5, 0.0), (-0.5, 0.5), (-3.7, -1.2), (-5.5, 8.0),  # Reflection region
+    (20.0, 1.0), (0.0, 30.0), (-50.0, 15.0), (-50.0, -15.0),  # Stirling region
+    (1e5, 1e5), (-1e3, 1e-3), (1e-5, 1e-5),  # Extreme values
+]
+
+# Generate table
+table = [(x, y, loggamma(mpc(x, y))) for x, y in cln_gamma_z_values]
+
+# Print in Rust code format
+print("const CLN_GAMMA_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(table)))
+for x, y, w in table:
+    print("    ({:.16e}, {:.16e}, {:.16e}, {:.16e}),".format(x, y, float(w.real), float(w.imag)))
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  cgamma
+# └──────────────────────────────────────────────────────────┘
+# z values to test
+cgamma_z_values = [
+    (1.0, 0.0), (5.0, 0.0), (0.5, 0.0), (-1.5, 0.0),  # Real values
+    (0.0, 1.0), (1.0, 1.0), (3.0, -4.0), (-2.5, 0.5),  # Complex values
+    (10.0, 10.0), (-10.5, 0.25), (150.0, 30.0),  # Larger values
+]
+
+# Generate table
+table = [(x, y, gamma(mpc(x, y))) for x, y in cgamma_z_values]
+
+# Print in Rust code format
+print("const CGAMMA_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(table)))
+for x, y, w in table:
+    print("    ({:.16e}, {:.16e}, {:.16e}, {:.16e}),".format(x, y, float(w.real), float(w.imag)))
+print("];")
@@ -0,0 +1,35 @@
+from mpmath import mp, mpf, cos, erfi, exp, lu_solve, matrix, pi, sqrt
+
+mp.dps = 40
+
+# ┌──────────────────────────────────────────────────────────┐
+#  Coefficients for im_w_of_x in src/dawson.rs
+# └──────────────────────────────────────────────────────────┘
+# Im(w(x)) = 2 D(x) / sqrt(pi) is interpolated on each subrange j <= y100 < j + 1
+# of y100 = 100 / (1 + x) by a polynomial of degree 8 in t = 2 y100 - (2j + 1),
+# using the Chebyshev nodes of the first kind in t.
+N_CHEB = 97
+N_TERMS = 9
+
+
+def im_w(x):
+    return 2 / sqrt(pi) * exp(-x * x) * sqrt(pi) / 2 * erfi(x)
+
+
+nodes = [cos(pi * (k + mpf(1) / 2) / N_TERMS) for k in range(N_TERMS)]
+vandermonde = matrix([[t**i for i in range(N_TERMS)] for t in nodes])
+
+coeffs = []
+for j in range(N_CHEB):
+    values = []
+    for t in nodes:
+        y100 = (t + 2 * j + 1) / 2
+        values.append(im_w(100 / y100 - 1))
+    c = lu_solve(vandermonde, matrix(values))
+    coeffs.extend(float(c[i]) for i in range(N_TERMS))
+
+# Print in Rust code format
+print("const W_IM_CHEBYSHEV_COEFFS: [f64; N_CHEB * N_TERMS] = [")
+for c in coeffs:
+    print("    {},".format(repr(c)))
+print("];")
@@ -0,0 +1,25 @@
+from mpmath import mp, erfi, exp, mpf, pi, sqrt
+
+mp.dps = 50
+
+# x values to test
+x_values = [
+    1e-10, 0.01, 0.05, 0.14, -0.5, 0.9241388730, 1.5, -3.0,  # Around the maximum
+    10.0, 44.9, 45.1, -1e3, 1e8, 1e300,  # Asymptotic region
+]
+
+
+def dawson(x):
+    x = mpf(x)
+    if x > 1e100:
+        return 1 / (2 * x)
+    return sqrt(pi) / 2 * exp(-x * x) * erfi(x)
+
+
+table = [(x, dawson(x)) for x in x_values]
+
+# Print in Rust code format
+print("const DAWSON_TABLE: [(f64, f64); {}] = [".format(len(table)))
+for x, y in table:
+    print("    ({:.16e}, {:.16e}),".format(x, float(y)))
+print("];")
@@ -1,4 +1,5 @@
-from scipy.special import erf, erfc, erfinv, erfcinv
+from scipy.special import erf, erfc, erfi, erfinv, erfcinv
+import mpmath
 
 # x values to test for erf and erfc
 x_values = [
@@ -19,6 +20,21 @@ x_values = [
 erf_table = [(x, erf(x)) for x in x_values]
 erfc_table = [(x, erfc(x)) for x in x_values]
 
+# x values to test for erfi, up to its overflow threshold near 26.71
+erfi_x_values = [-3.0, -0.5, 0.0, 0.5, 1.5, 3.0, 1e-10, 10.0, -20.0, 26.6]
+erfi_table = [(x, erfi(x)) for x in erfi_x_values]
+
+# x values to test for ln_erfc and ln_erf, reaching where erfc underflows
+# (computed with mpmath, since scipy has no logarithmic error functions)
+mpmath.mp.dps = 50
+ln_erfc_x_values = [-1e5, -5.0, -0.5, -1e-10, 0.0, 1e-10, 0.3, 0.5, 2.0, 10.0, 30.0, 1e3, 1e5]
+ln_erfc_table = [(x, mpmath.log(mpmath.erfc(x))) for x in ln_erfc_x_values]
+ln_erf_x_values = [-10.0, -3.0, -0.3, 1e-300, 1e-10, 0.5, 2.0, 6.0, 20.0]
+ln_erf_table = [
+    (x, mpmath.log(abs(mpmath.erf(x))) if abs(x) < 1 else mpmath.log1p(-mpmath.erfc(abs(x))))
+    for x in ln_erf_x_values
+]
+
 # p values to test for inverf and inverfc
 p_values = [
     -0.99,  # Near -1 (for inverf only)
@@ -46,6 +62,21 @@ for x, y in erfc_table:
     print("    ({:.14e}, {:.14e}),".format(x, y))
 print("];")
 
+print("\nconst ERFI_TABLE: [(f64, f64); {}] = [".format(len(erfi_table)))
+for x, y in erfi_table:
+    print("    ({:.14e}, {:.14e}),".format(x, y))
+print("];")
+
+print("\nconst LN_ERFC_TABLE: [(f64, f64); {}] = [".format(len(ln_erfc_table)))
+for x, y in ln_erfc_table:
+    print("    ({:.16e}, {:.16e}),".format(x, float(y)))
+print("];")
+
+print("\nconst LN_ERF_TABLE: [(f64, f64); {}] = [".format(len(ln_erf_table)))
+for x, y in ln_erf_table:
+    print("    ({:.16e}, {:.16e}),".format(x, float(y)))
+print("];")
+
 print("\nconst INVERF_TABLE: [(f64, f64); {}] = [".format(len(inverf_table)))
 for p, x in inverf_table:
     print("    ({:.14e}, {:.14e}),".format(p, x))
@@ -0,0 +1,52 @@
+from mpmath import mp, mpf, gamma, rgamma, loggamma, exp
+
+mp.dps = 50
+
+
+def ratio(a, b):
+    a, b = mpf(a), mpf(b)
+    if a > 0 and b > 0:
+        return exp(loggamma(a) - loggamma(b))
+    return gamma(a) * rgamma(b)
+
+
+# ┌──────────────────────────────────────────────────────────┐
+#  gamma_ratio
+# └──────────────────────────────────────────────────────────┘
+# (a, b) values to test
+gamma_ratio_values = [
+    (2.0, 1.0), (0.5, 1.5), (3.7, 1.2), (10.0, 20.0),  # Small arguments
+    (200.0, 190.5), (1000.5, 1000.0), (1e8, 1e8 + 3.5),  # Large, close arguments
+    (220.0, 100.0), (40.0, 180.0), (1e-8, 150.0),  # Large, distant arguments
+    (-2.5, -1.5), (-10.3, -10.7), (-0.5, 3.0), (4.5, -3.5),  # Negative arguments
+]
+
+# Generate table
+table = [(a, b, ratio(a, b)) for a, b in gamma_ratio_values]
+
+# Print in Rust code format
+print("const GAMMA_RATIO_TABLE: [(f64, f64, f64); {}] = [".format(len(table)))
+for a, b, r in table:
+    print("    ({:.16e}, {:.16e}, {:.16e}),".format(a, b, float(r)))
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  gamma_delta_ratio
+# └──────────────────────────────────────────────────────────┘
+# (x, delta) values to test
+gamma_delta_ratio_values = [
+    (1.0, 1.0), (2.5, 0.5), (0.1, 3.0), (10.0, -5.5),  # Small arguments
+    (1e3, 1e-10), (1e6, 1e-8), (1e10, 1e-3), (1e15, 0.5),  # Large x, small delta
+    (5.0, 1e-15), (1e20, 2.0),  # Delta below the resolution of x
+    (150.0, 60.0), (300.0, -120.0),  # Large shifts
+    (-2.5, 0.25), (0.5, -1.0),  # Negative arguments
+]
+
+# Generate table
+table = [(x, d, ratio(mpf(x) + mpf(d), x)) for x, d in gamma_delta_ratio_values]
+
+# Print in Rust code format
+print("const GAMMA_DELTA_RATIO_TABLE: [(f64, f64, f64); {}] = [".format(len(table)))
+for x, d, r in table:
+    print("    ({:.16e}, {:.16e}, {:.16e}),".format(x, d, float(r)))
+print("];")
@@ -0,0 +1,151 @@
+from mpmath import mp, mpf, betainc, diff, exp, findroot, log, log1p, loggamma
+
+mp.dps = 50
+
+
+def lower(a, b, x):
+    # B(x; a, b) = x^a (1-x)^b / a * 2F1(a+b, 1; a+1; x), summed below the mean
+    s = t = mpf(1)
+    n = 0
+    while t > s * mpf(10) ** -45:
+        t *= (a + b + n) * x / (a + 1 + n)
+        s += t
+        n += 1
+    return exp(a * log(x) + b * log1p(-x)) / a * s
+
+
+def incomplete_beta(a, b, x):
+    # Returns (B(x; a, b), B(a, b) - B(x; a, b), B(a, b))
+    a, b, x = mpf(a), mpf(b), mpf(x)
+    beta = exp(loggamma(a) + loggamma(b) - loggamma(a + b))
+    if x < a / (a + b):
+        lo = lower(a, b, x)
+        return lo, beta - lo, beta
+    up = lower(b, a, 1 - x)
+    return beta - up, up, beta
+
+
+# ┌──────────────────────────────────────────────────────────┐
+#  betaic, beta_lower & beta_upper
+# └──────────────────────────────────────────────────────────┘
+# (a, b, x) values to test
+values = [
+    (0.5, 0.5, 0.1), (2.0, 3.0, 0.4), (5.0, 0.5, 0.9), (10.0, 10.0, 0.5), (0.1, 0.1, 0.99),  # Bulk
+    (2.0, 3.0, 1e-10), (1.0, 50.0, 0.7), (20.0, 5.0, 0.999), (0.5, 30.0, 0.9),  # Small tails
+    (1e-3, 2.0, 0.3), (3.0, 1e4, 1e-3), (4000.0, 5000.0, 0.5), (4000.0, 5000.0, 0.47),  # Extreme parameters
+]
+
+# The non-regularized functions underflow for the extreme parameters
+unreg_values = [(a, b, x) for a, b, x in values if a < 1000]
+
+# Print in Rust code format
+for name, f, vals in [
+    ("BETAIC_TABLE", lambda lo, up, beta: up / beta, values),
+    ("BETA_LOWER_TABLE", lambda lo, up, beta: lo, unreg_values),
+    ("BETA_UPPER_TABLE", lambda lo, up, beta: up, unreg_values),
+]:
+    print("const {}: [(f64, f64, f64, f64); {}] = [".format(name, len(vals)))
+    for a, b, x in vals:
+        print("    ({:.16e}, {:.16e}, {:.16e}, {:.16e}),".format(a, b, x, float(f(*incomplete_beta(a, b, x)))))
+    print("];")
+    print()
+
+# ┌──────────────────────────────────────────────────────────┐
+#  betai_derivative_a & betai_derivative_b
+# └──────────────────────────────────────────────────────────┘
+# (a, b, x) values to test
+derivative_values = [
+    (0.5, 0.5, 0.1), (2.0, 3.0, 0.4), (5.0, 0.5, 0.9), (10.0, 10.0, 0.5), (0.1, 0.1, 0.99),  # Bulk
+    (2.0, 3.0, 1e-10), (1.0, 50.0, 0.7), (20.0, 5.0, 0.999), (0.5, 30.0, 0.9), (1e-3, 2.0, 0.3),  # Tails
+    (150.0, 200.0, 0.45),  # Large parameters
+]
+
+
+def di_da(a, b, x):
+    a, b, x = mpf(a), mpf(b), mpf(x)
+    if x < a / (a + b):
+        return diff(lambda t: betainc(t, b, 0, x, regularized=True), a)
+    return -diff(lambda t: betainc(t, b, x, 1, regularized=True), a)
+
+
+def di_db(a, b, x):
+    a, b, x = mpf(a), mpf(b), mpf(x)
+    if x < a / (a + b):
+        return diff(lambda t: betainc(a, t, 0, x, regularized=True), b)
+    return -diff(lambda t: betainc(a, t, x, 1, regularized=True), b)
+
+
+for name, f in [("BETAI_DERIVATIVE_A_TABLE", di_da), ("BETAI_DERIVATIVE_B_TABLE", di_db)]:
+    print("const {}: [(f64, f64, f64, f64); {}] = [".format(name, len(derivative_values)))
+    for a, b, x in derivative_values:
+        print("    ({:.16e}, {:.16e}, {:.16e}, {:.16e}),".format(a, b, x, float(f(a, b, x))))
+    print("];")
+    print()
+
+# ┌──────────────────────────────────────────────────────────┐
+#  invbetai_a & invbetai_b
+# └──────────────────────────────────────────────────────────┘
+# (p, shape, x) values to test; shape is b for invbetai_a and a for invbetai_b
+inv_values = [
+    (0.5, 2.0, 0.3), (0.1, 5.0, 0.5), (0.9, 1.0, 0.2), (0.025, 10.0, 0.6), (0.3, 0.5, 0.05),  # Bulk
+    (1e-10, 3.0, 0.1), (1e-30, 20.0, 0.7), (0.999, 50.0, 0.9), (0.6, 200.0, 0.4),  # Tails and large shapes
+]
+
+
+def invbetai_shape(p, x, f):
+    p, x = mpf(p), mpf(x)
+    g = lambda s: f(s) - p
+    # Bisection on log(s) to locate the root, then refine
+    lo, hi = mpf(10) ** -30, mpf(10) ** 6
+    for _ in range(200):
+        mid = (lo * hi) ** 0.5
+        if (g(mid) > 0) == (g(lo) > 0):
+            lo = mid
+        else:
+            hi = mid
+    return findroot(g, (lo, hi), solver="anderson")
+
+
+def betai(a, b, x):
+    lo, up, beta = incomplete_beta(a, b, x)
+    return lo / beta
+
+
+for name, f in [
+    ("INVBETAI_A_TABLE", lambda b, x: lambda a: betai(a, b, x)),
+    ("INVBETAI_B_TABLE", lambda a, x: lambda b: betai(a, b, x)),
+]:
+    print("const {}: [(f64, f64, f64, f64); {}] = [".format(name, len(inv_values)))
+    for p, s, x in inv_values:
+        print("    ({:.16e}, {:.16e}, {:.16e}, {:.16e}),".format(p, s, x, float(invbetai_shape(p, x, f(s, x)))))
+    print("];")
+    print()
+
+# ┌──────────────────────────────────────────────────────────┐
+#  invbetai (small shapes and far tails)
+# └──────────────────────────────────────────────────────────┘
+# (a, b, p) values to test
+invbetai_values = [
+    (0.01, 0.01, 0.3), (0.01, 0.01, 0.5), (0.02, 0.5, 0.3), (0.5, 0.02, 0.01), (0.1, 10.0, 0.999),  # Small shapes
+    (100.0, 1.0, 1e-20), (2.0, 3.0, 1e-100), (50.0, 0.5, 0.5), (5.0, 5.0, 0.9999999),  # Far tails
+]
+
+
+def invbetai(a, b, p):
+    a, b, p = mpf(a), mpf(b), mpf(p)
+    g = lambda x: betainc(a, b, 0, x, regularized=True) - p
+    # Bisection, geometric while the bracket spans many decades
+    lo, hi = mpf(0), mpf(1)
+    while hi - lo > hi * mpf(10) ** -40:
+        mid = (lo + hi) / 2 if lo > hi / 4 else (max(lo, mpf(10) ** -400) * hi) ** 0.5
+        if g(mid) < 0:
+            lo = mid
+        else:
+            hi = mid
+    return lo
+
+
+print("const INVBETAI_INFO_TABLE: [(f64, f64, f64, f64); {}] = [".format(len(invbetai_values)))
+for a, b, p in invbetai_values:
+    print("    ({:.16e}, {:.16e}, {:.16e}, {:.16e}),".format(a, b, p, float(invbetai(a, b, p))))
+print("];")
@@ -0,0 +1,48 @@
+from mpmath import mp, psi
+
+mp.dps = 50
+
+# ┌──────────────────────────────────────────────────────────┐
+#  digamma
+# └──────────────────────────────────────────────────────────┘
+# x values to test
+digamma_x_values = [
+    1e-10, 1e-5, 0.1, 0.5,  # Values between 0 and 1
+    1.0, 1.4616321449683622, 1.5, 2.0,  # Around the positive root
+    3.5, 7.0, 10.0, 50.0,  # Moderate values
+    1e5, 1e10, 1e100,  # Very large values
+    -0.5, -1.5, -2.25, -10.1, -100.9,  # Negative non-integer values
+]
+
+# Generate table
+table = [(x, psi(0, x)) for x in digamma_x_values]
+
+# Print in Rust code format
+print("const DIGAMMA_TABLE: [(f64, f64); {}] = [".format(len(table)))
+for x, y in table:
+    print("    ({:.16e}, {:.16e}),".format(x, float(y)))
+print("];")
+
+# ┌──────────────────────────────────────────────────────────┐
+#  polygamma
+# └──────────────────────────────────────────────────────────┘
+# (n, x) values to test
+polygamma_values = [
+    (1, 1e-5), (1, 0.5), (1, 1.0), (1, 3.5), (1, 30.0), (1, 1e8),
+    (2, 0.1), (2, 1.0), (2, 10.0), (2, 1e3),
+    (3, 0.25), (3, 2.0), (3, 100.0),
+    (5, 0.5), (5, 7.5),
+    (10, 1.0), (10, 25.0),
+    (30, 3.0), (100, 0.5), (200, 10.0),
+    (1, -0.5), (1, -2.75), (2, -0.5), (2, -3.3), (3, -1.25), (4, -0.1),
+    (4, -20.5), (6, -5.5), (10, -1.5), (10, -0.5),  # Negative half-integers
+]
+
+# Generate table
+table = [(n, x, psi(n, x)) for n, x in polygamma_values]
+
+# Print in Rust code format
+print("const POLYGAMMA_TABLE: [(usize, f64, f64); {}] = [".format(len(table)))
+for n, x, y in table:
+    print("    ({}, {:.16e}, {:.16e}),".format(n, x, float(y)))
+print("];")
@@ -2,10 +2,17 @@
 //!
 //! It includes the following main functions:
 //! - `beta`: Calculates the beta function.
+//! - `ln_beta`: Calculates the natural logarithm of the beta function.
 //! - `betai`: Calculates the regularized incomplete beta function.
+//! - `betaic`: Calculates the complement of the regularized incomplete beta function.
+//! - `beta_lower`, `beta_upper`: Calculate the non-regularized incomplete beta functions.
+//! - `betai_derivative_a`, `betai_derivative_b`: Calculate the partial derivatives of I_x(a,b) in a and b.
 //! - `invbetai`: Calculates the inverse of the regularized incomplete beta function.
+//! - `invbetai_info`: Calculates the inverse along with its residual and iteration count.
+//! - `invbetai_a`, `invbetai_b`: Calculate the shape parameter a or b for which I_x(a,b) = p.
 
-use crate::{ln_gamma, EPS, FPMIN, W, Y};
+use crate::gamma::{stirling_correction, HALF_LN_2PI};
+use crate::{digamma, gamma, gamma_delta_ratio, ln_gamma, ln_gamma_sign, EPS, FPMIN, W, Y};
 const SWITCH: usize = 3000;
 
 /// Calculates the beta function.
@@ -18,6 +25,12 @@ const SWITCH: usize = 3000;
 ///
 /// $$ B(z,w) = \frac{\Gamma(z)\Gamma(w)}{\Gamma(z+w)} $$
 ///
+/// which continues it to negative arguments. At a non-positive integer `z` the limit
+///
+/// $$ B(-n, w) = (-1)^w B(1+n-w, w) $$
+///
+/// is finite when `w` is a positive integer with $w \le n$; otherwise `z` is a pole.
+///
 /// # Arguments
 ///
 /// * `z` - First parameter
@@ -25,9 +38,111 @@ const SWITCH: usize = 3000;
 ///
 /// # Returns
 ///
-/// The value of the beta function $B(z,w)$
+/// The value of the beta function $B(z,w)$, which is infinite at its poles and zero
+/// when only $z+w$ is a non-positive integer
 pub fn beta(z: f64, w: f64) -> f64 {
-    (ln_gamma(z) + ln_gamma(w) - ln_gamma(z + w)).exp()
+    if z.is_nan() || w.is_nan() {
+        return f64::NAN;
+    }
+    if let Some(r) = beta_negint(z, w) {
+        return r;
+    }
+    let (small, large) = if z < w { (z, w) } else { (w, z) };
+    if small < 0f64 {
+        let sum = small + large;
+        if sum <= 0f64 && sum == sum.floor() {
+            return 0f64;
+        }
+        let r = gamma(small) / gamma_delta_ratio(large, small);
+        if r.is_normal() {
+            return r;
+        }
+        let sign = ln_gamma_sign(z).1 * ln_gamma_sign(w).1 * ln_gamma_sign(z + w).1;
+        return sign * ln_beta(z, w).exp();
+    }
+    if small <= 171f64 {
+        // B(z,w) = Γ(small) / (Γ(large + small) / Γ(large))
+        let r = gamma(small) / gamma_delta_ratio(large, small);
+        if r.is_normal() {
+            return r;
+        }
+    }
+    ln_beta(z, w).exp()
+}
+
+/// Calculates the natural logarithm of the beta function.
+///
+/// $$ \ln |B(a,b)| = \ln \left| \frac{\Gamma(a)\Gamma(b)}{\Gamma(a+b)} \right| $$
+///
+/// When both parameters are at least 8 the Stirling series of the three gamma functions is
+/// combined analytically (DiDonato & Morris, 1992):
+///
+/// $$ \ln B(a,b) = \tfrac{1}{2}\ln 2\pi - \tfrac{1}{2}\ln b - \left(a - \tfrac{1}{2}\right)\ln\frac{a}{a+b}
+/// - b \ln\left(1 + \frac{a}{b}\right) + \Delta(a) + \Delta(b) - \Delta(a+b), \quad a \le b $$
+///
+/// where $\Delta$ is the remainder of the Stirling approximation. When only one parameter
+/// is large, $\ln\Gamma(b)/\Gamma(a+b)$ is expanded in the same way, so no term is formed
+/// that overflows or cancels catastrophically.
+///
+/// # Arguments
+///
+/// * `a` - First parameter
+/// * `b` - Second parameter
+///
+/// # Returns
+///
+/// The natural logarithm of the absolute value of $B(a,b)$; use [`beta`] for its sign.
+pub fn ln_beta(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        return f64::NAN;
+    }
+    if let Some(r) = beta_negint(a, b) {
+        return r.abs().ln();
+    }
+    let (small, large) = if a < b { (a, b) } else { (b, a) };
+    if small >= 8f64 {
+        let h = small / large;
+        let c = h / (1f64 + h);
+        let w = stirling_correction(small) + stirling_correction(large)
+            - stirling_correction(small + large);
+        return HALF_LN_2PI - 0.5 * large.ln() + w + (small - 0.5) * c.ln() - large * h.ln_1p();
+    }
+
+    let sum = small + large;
+    if sum <= 0f64 && sum == sum.floor() {
+        return f64::NEG_INFINITY;
+    }
+    let r = gamma(small) / gamma_delta_ratio(large, small);
+    if r.is_normal() {
+        return r.abs().ln();
+    }
+    if small > 0f64 && large >= 8f64 {
+        // ln Γ(large) / Γ(large + small) from the Stirling series
+        let h = small / large;
+        let ln_ratio = stirling_correction(large)
+            - stirling_correction(large + small)
+            - small * large.ln()
+            - ((large - 0.5) * h.ln_1p() + small * (h.ln_1p() - 1f64));
+        return ln_gamma(small) + ln_ratio;
+    }
+    ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b)
+}
+
+/// Limit of B(z,w) at a non-positive integer parameter, following Cephes
+fn beta_negint(z: f64, w: f64) -> Option<f64> {
+    let (n, w) = if z <= 0f64 && z == z.floor() {
+        (z, w)
+    } else if w <= 0f64 && w == w.floor() {
+        (w, z)
+    } else {
+        return None;
+    };
+    if w == w.floor() && 1f64 - n - w > 0f64 {
+        let sign = if w % 2f64 == 0f64 { 1f64 } else { -1f64 };
+        Some(sign * beta(1f64 - n - w, w))
+    } else {
+        Some(f64::INFINITY)
+    }
 }
 
 // =============================================================================
@@ -60,15 +175,68 @@ pub fn betai(a: f64, b: f64, x: f64) -> f64 {
     if x == 0f64 || x == 1f64 {
         return x;
     }
+    incbeta(a, b, x, IncBeta::I)
+}
+
+/// Calculates the complement of the regularized incomplete beta function.
+///
+/// $$ 1 - I_x(a,b) = \frac{1}{B(a,b)} \int_x^1 t^{a-1} (1-t)^{b-1} dt $$
+///
+/// The upper tail is evaluated directly, so it keeps its relative precision where
+/// `1 - betai(a, b, x)` would cancel.
+///
+/// # Arguments
+///
+/// * `a` - First shape parameter
+/// * `b` - Second shape parameter
+/// * `x` - Lower limit of integration (between 0 and 1)
+///
+/// # Returns
+///
+/// The value of $1 - I_x(a,b)$
+///
+/// # Panics
+///
+/// Panics if `a` ≤ 0, if `b` ≤ 0 or if x is not in the range `0..=1`.
+pub fn betaic(a: f64, b: f64, x: f64) -> f64 {
+    assert!(a > 0f64 && b > 0f64, "Bad a or b in routine betaic");
+    assert!((0f64..=1f64).contains(&x), "Bad x in routine betaic");
+    if x == 0f64 || x == 1f64 {
+        return 1f64 - x;
+    }
+    incbeta(a, b, x, IncBeta::Ic)
+}
+
+/// Kinds of Incomplete Beta function
+#[derive(Debug, Copy, Clone)]
+enum IncBeta {
+    I,
+    Ic,
+}
+
+/// Selects the continued fraction on the side of `x` where it converges quickly
+fn incbeta(a: f64, b: f64, x: f64, tail: IncBeta) -> f64 {
     let switch = SWITCH as f64;
     if a > switch && b > switch {
-        return betaiapprox(a, b, x);
+        return betaiapprox(a, b, x, tail);
     }
-    let bt = (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1f64 - x).ln()).exp();
-    if x < (a + 1f64) / (a + b * 2f64) {
-        bt * betacf(a, b, x) / a
+    let bt = (a * x.ln() + b * (-x).ln_1p() - ln_beta(a, b)).exp();
+    if x < (a + 1f64) / (a + b + 2f64) {
+        let i = bt * betacf(a, b, x) / a;
+        match tail {
+            IncBeta::I => i,
+            // 1 - i cancels when the lower tail is close to 1, as for tiny a;
+            // the continued fraction on the other side is slower but keeps the digits
+            IncBeta::Ic if i > 0.99 => bt * betacf(b, a, 1f64 - x) / b,
+            IncBeta::Ic => 1f64 - i,
+        }
     } else {
-        1f64 - bt * betacf(b, a, 1f64 - x) / b
+        let ic = bt * betacf(b, a, 1f64 - x) / b;
+        match tail {
+            IncBeta::I if ic > 0.99 => bt * betacf(a, b, x) / a,
+            IncBeta::I => 1f64 - ic,
+            IncBeta::Ic => ic,
+        }
     }
 }
 
@@ -118,7 +286,7 @@ fn betacf(a: f64, b: f64, x: f64) -> f64 {
 }
 
 /// Incomplete beta by Gauss Legendre quadrature
-fn betaiapprox(a: f64, b: f64, x: f64) -> f64 {
+fn betaiapprox(a: f64, b: f64, x: f64, tail: IncBeta) -> f64 {
     let a1 = a - 1f64;
     let b1 = b - 1f64;
     let mu = a / (a + b);
@@ -126,31 +294,265 @@ fn betaiapprox(a: f64, b: f64, x: f64) -> f64 {
     let lnmuc = (1f64 - mu).ln();
     let mut t = (a * b / ((a + b).powi(2) * (a + b + 1f64))).sqrt();
     let xu = if x > a / (a + b) {
-        if x >= 1f64 {
-            return 1f64;
-        }
         1f64.min((mu + 10f64 * t).max(x + 5f64 * t))
     } else {
-        if x <= 0f64 {
-            return 0f64;
-        }
         0f64.max((mu - 10f64 * t).min(x - 5f64 * t))
     };
     let mut sum = 0f64;
     for j in 0..18 {
         t = x + (xu - x) * Y[j];
-        sum += W[j] * (a1 * (t.ln() - lnmu) + b1 * (1f64 - t).ln() - lnmuc).exp();
+        sum += W[j] * (a1 * (t.ln() - lnmu) + b1 * ((1f64 - t).ln() - lnmuc)).exp();
+    }
+    let ans = sum * (xu - x) * (a1 * lnmu + b1 * lnmuc - ln_beta(a, b)).exp();
+    match tail {
+        IncBeta::I => {
+            if ans.is_sign_positive() {
+                1f64 - ans
+            } else {
+                -ans
+            }
+        }
+        IncBeta::Ic => {
+            if ans.is_sign_positive() {
+                ans
+            } else {
+                1f64 + ans
+            }
+        }
+    }
+}
+
+// =============================================================================
+// Incomplete Beta function (non-regularized)
+// =============================================================================
+/// Calculates the incomplete beta function.
+///
+/// $$ B(x;a,b) = \int_0^x t^{a-1} (1-t)^{b-1} dt = B(a,b) I_x(a,b) $$
+///
+/// Where the continued fraction for the lower tail converges, it is used without the
+/// normalization by $B(a,b)$; otherwise the result is $B(a,b) I_x(a,b)$.
+///
+/// # Arguments
+///
+/// * `a` - First shape parameter
+/// * `b` - Second shape parameter
+/// * `x` - Upper limit of integration (between 0 and 1)
+///
+/// # Returns
+///
+/// The value of the incomplete beta function $B(x;a,b)$
+///
+/// # Panics
+///
+/// Panics if `a` ≤ 0, if `b` ≤ 0 or if x is not in the range `0..=1`.
+pub fn beta_lower(a: f64, b: f64, x: f64) -> f64 {
+    assert!(a > 0f64 && b > 0f64, "Bad a or b in routine beta_lower");
+    assert!((0f64..=1f64).contains(&x), "Bad x in routine beta_lower");
+    if x == 0f64 {
+        return 0f64;
+    }
+    let switch = SWITCH as f64;
+    if x < (a + 1f64) / (a + b + 2f64) && !(a > switch && b > switch) {
+        (a * x.ln() + b * (-x).ln_1p()).exp() * betacf(a, b, x) / a
+    } else {
+        beta(a, b) * betai(a, b, x)
+    }
+}
+
+/// Calculates the complementary incomplete beta function.
+///
+/// $$ B(a,b) - B(x;a,b) = \int_x^1 t^{a-1} (1-t)^{b-1} dt = B(a,b) \left(1 - I_x(a,b)\right) $$
+///
+/// # Arguments
+///
+/// * `a` - First shape parameter
+/// * `b` - Second shape parameter
+/// * `x` - Lower limit of integration (between 0 and 1)
+///
+/// # Returns
+///
+/// The value of $B(a,b) - B(x;a,b)$
+///
+/// # Panics
+///
+/// Panics if `a` ≤ 0, if `b` ≤ 0 or if x is not in the range `0..=1`.
+pub fn beta_upper(a: f64, b: f64, x: f64) -> f64 {
+    assert!(a > 0f64 && b > 0f64, "Bad a or b in routine beta_upper");
+    assert!((0f64..=1f64).contains(&x), "Bad x in routine beta_upper");
+    if x == 1f64 {
+        return 0f64;
     }
-    let ans = sum
-        * (xu - x)
-        * (a1 * lnmu - ln_gamma(a) + b1 * lnmuc - ln_gamma(b) + ln_gamma(a + b)).exp();
-    if ans > 0f64 {
-        1f64 - ans
+    let switch = SWITCH as f64;
+    if x >= (a + 1f64) / (a + b + 2f64) && !(a > switch && b > switch) {
+        (a * x.ln() + b * (-x).ln_1p()).exp() * betacf(b, a, 1f64 - x) / b
     } else {
-        -ans
+        beta(a, b) * betaic(a, b, x)
     }
 }
 
+// =============================================================================
+// Derivatives of the incomplete Beta function
+// =============================================================================
+/// Calculates the derivative of I_x(a,b) with respect to a.
+///
+/// $$ \frac{\partial I_x(a,b)}{\partial a} = \frac{1}{B(a,b)} \int_0^x t^{a-1} (1-t)^{b-1} \ln t \, dt - (\psi(a) - \psi(a+b)) I_x(a,b) $$
+///
+/// The continued fraction of `betai` is differentiated along with its convergents,
+/// on whichever side of $x$ it converges.
+///
+/// # Arguments
+///
+/// * `a` - First shape parameter
+/// * `b` - Second shape parameter
+/// * `x` - Upper limit of integration (between 0 and 1)
+///
+/// # Returns
+///
+/// The value of ∂I_x(a,b)/∂a
+///
+/// # Panics
+///
+/// Panics if `a` ≤ 0, if `b` ≤ 0 or if x is not in the range `0..=1`.
+pub fn betai_derivative_a(a: f64, b: f64, x: f64) -> f64 {
+    assert!(
+        a > 0f64 && b > 0f64,
+        "Bad a or b in routine betai_derivative_a"
+    );
+    assert!(
+        (0f64..=1f64).contains(&x),
+        "Bad x in routine betai_derivative_a"
+    );
+    if x == 0f64 || x == 1f64 {
+        return 0f64;
+    }
+    betai_derivatives(a, b, x).0
+}
+
+/// Calculates the derivative of I_x(a,b) with respect to b.
+///
+/// $$ \frac{\partial I_x(a,b)}{\partial b} = \frac{1}{B(a,b)} \int_0^x t^{a-1} (1-t)^{b-1} \ln(1-t) \, dt - (\psi(b) - \psi(a+b)) I_x(a,b) $$
+///
+/// # Arguments
+///
+/// * `a` - First shape parameter
+/// * `b` - Second shape parameter
+/// * `x` - Upper limit of integration (between 0 and 1)
+///
+/// # Returns
+///
+/// The value of ∂I_x(a,b)/∂b
+///
+/// # Panics
+///
+/// Panics if `a` ≤ 0, if `b` ≤ 0 or if x is not in the range `0..=1`.
+pub fn betai_derivative_b(a: f64, b: f64, x: f64) -> f64 {
+    assert!(
+        a > 0f64 && b > 0f64,
+        "Bad a or b in routine betai_derivative_b"
+    );
+    assert!(
+        (0f64..=1f64).contains(&x),
+        "Bad x in routine betai_derivative_b"
+    );
+    if x == 0f64 || x == 1f64 {
+        return 0f64;
+    }
+    betai_derivatives(a, b, x).1
+}
+
+/// (∂I/∂a, ∂I/∂b) from $I_x(a,b) = \frac{x^a (1-x)^b}{a B(a,b)} K(a,b,x)$,
+/// or from the same form of $1 - I_x(a,b)$ with a and b swapped above the switch point
+fn betai_derivatives(a: f64, b: f64, x: f64) -> (f64, f64) {
+    let bt = (a * x.ln() + b * (-x).ln_1p() - ln_beta(a, b)).exp();
+    let psi_ab = digamma(a + b);
+    if x < (a + 1f64) / (a + b + 2f64) {
+        let (k, dk_a, dk_b) = betacf_derivatives(a, b, x);
+        let da = k * (x.ln() - digamma(a) + psi_ab - 1f64 / a) + dk_a;
+        let db = k * ((-x).ln_1p() - digamma(b) + psi_ab) + dk_b;
+        (bt / a * da, bt / a * db)
+    } else {
+        let (k, dk_b, dk_a) = betacf_derivatives(b, a, 1f64 - x);
+        let da = k * (x.ln() - digamma(a) + psi_ab) + dk_a;
+        let db = k * ((-x).ln_1p() - digamma(b) + psi_ab - 1f64 / b) + dk_b;
+        (-bt / b * da, -bt / b * db)
+    }
+}
+
+/// Continued fraction of `betacf` with its derivatives in a and b
+///
+/// $$ K = \cfrac{1}{1 + \cfrac{d_1}{1 + \cfrac{d_2}{1 + \cdots}}} $$
+///
+/// with $d_{2m+1} = -\frac{(a+m)(a+b+m)x}{(a+2m)(a+2m+1)}$ and
+/// $d_{2m} = \frac{m(b-m)x}{(a+2m-1)(a+2m)}$.
+fn betacf_derivatives(a: f64, b: f64, x: f64) -> (f64, f64, f64) {
+    // Convergents A_n / B_n with a_1 = 1, a_{n+1} = d_n and b_n = 1,
+    // along with their derivatives in a (suffix _a) and b (suffix _b)
+    let (mut a_prev, mut a_cur, mut b_prev, mut b_cur) = (1f64, 0f64, 0f64, 1f64);
+    let (mut a_prev_a, mut a_cur_a, mut b_prev_a, mut b_cur_a) = (0f64, 0f64, 0f64, 0f64);
+    let (mut a_prev_b, mut a_cur_b, mut b_prev_b, mut b_cur_b) = (0f64, 0f64, 0f64, 0f64);
+    let mut h = 0f64;
+    let mut h_a = 0f64;
+    let mut h_b = 0f64;
+    for n in 1..10000 {
+        let (an, an_a, an_b) = if n == 1 {
+            (1f64, 0f64, 0f64)
+        } else if n % 2 == 0 {
+            // d_{2m+1}, m = (n - 2) / 2
+            let m = ((n - 2) / 2) as f64;
+            let m2 = 2f64 * m;
+            let d = -(a + m) * (a + b + m) * x / ((a + m2) * (a + m2 + 1f64));
+            let d_a = d
+                * (1f64 / (a + m) + 1f64 / (a + b + m) - 1f64 / (a + m2) - 1f64 / (a + m2 + 1f64));
+            let d_b = -(a + m) * x / ((a + m2) * (a + m2 + 1f64));
+            (d, d_a, d_b)
+        } else {
+            // d_{2m}, m = (n - 1) / 2
+            let m = ((n - 1) / 2) as f64;
+            let m2 = 2f64 * m;
+            let den = (a + m2 - 1f64) * (a + m2);
+            let d = m * (b - m) * x / den;
+            let d_a = -d * (1f64 / (a + m2 - 1f64) + 1f64 / (a + m2));
+            let d_b = m * x / den;
+            (d, d_a, d_b)
+        };
+        let a_next = a_cur + an * a_prev;
+        let a_next_a = a_cur_a + an_a * a_prev + an * a_prev_a;
+        let a_next_b = a_cur_b + an_b * a_prev + an * a_prev_b;
+        let b_next = b_cur + an * b_prev;
+        let b_next_a = b_cur_a + an_a * b_prev + an * b_prev_a;
+        let b_next_b = b_cur_b + an_b * b_prev + an * b_prev_b;
+        // Rescale to keep the recurrences in range
+        let s = 1f64 / b_next;
+        a_prev = a_cur * s;
+        a_prev_a = a_cur_a * s;
+        a_prev_b = a_cur_b * s;
+        b_prev = b_cur * s;
+        b_prev_a = b_cur_a * s;
+        b_prev_b = b_cur_b * s;
+        a_cur = a_next * s;
+        a_cur_a = a_next_a * s;
+        a_cur_b = a_next_b * s;
+        b_cur = 1f64;
+        b_cur_a = b_next_a * s;
+        b_cur_b = b_next_b * s;
+
+        let h_new = a_cur;
+        let h_new_a = a_cur_a - a_cur * b_cur_a;
+        let h_new_b = a_cur_b - a_cur * b_cur_b;
+        let done = n > 2
+            && (h_new - h).abs() <= h_new.abs() * EPS
+            && (h_new_a - h_a).abs() <= h_new_a.abs() * EPS
+            && (h_new_b - h_b).abs() <= h_new_b.abs() * EPS;
+        h = h_new;
+        h_a = h_new_a;
+        h_b = h_new_b;
+        if done {
+            break;
+        }
+    }
+    (h, h_a, h_b)
+}
+
 // =============================================================================
 // Inverse of Incomplete Beta function
 // =============================================================================
@@ -160,6 +562,10 @@ fn betaiapprox(a: f64, b: f64, x: f64) -> f64 {
 ///
 /// $$ p = I_x(a,b) = \frac{1}{B(a,b)} \int_0^x t^{a-1} (1-t)^{b-1} dt $$
 ///
+/// For p > 1/2 the complementary tail $1 - I_x(a,b) = 1 - p$ is solved instead.
+/// Halley steps are kept inside a bracket around the root and fall back to bisection
+/// when they leave it, so the iteration always converges.
+///
 /// # Arguments
 ///
 /// * `p` - The probability value (between 0 and 1)
@@ -169,20 +575,124 @@ fn betaiapprox(a: f64, b: f64, x: f64) -> f64 {
 /// # Returns
 ///
 /// The value of $x$ for which $I_x(a,b) = p$
+///
+/// # Panics
+///
+/// Panics if `a` ≤ 0 or `b` ≤ 0.
 pub fn invbetai(p: f64, a: f64, b: f64) -> f64 {
+    invbetai_info(p, a, b).0
+}
+
+/// Calculates the inverse of the regularized incomplete beta function and reports
+/// how well it converged.
+///
+/// This is `invbetai` together with the residual $I_x(a,b) - p$ at the returned $x$ and
+/// the number of iterations. The residual is evaluated in the tail that was solved, so
+/// it keeps its relative precision for p close to 1.
+///
+/// # Arguments
+///
+/// * `p` - The probability value (between 0 and 1)
+/// * `a` - First shape parameter
+/// * `b` - Second shape parameter
+///
+/// # Returns
+///
+/// A tuple `(x, residual, iterations)` with $I_x(a,b) - p$ = `residual`
+///
+/// # Panics
+///
+/// Panics if `a` ≤ 0 or `b` ≤ 0.
+pub fn invbetai_info(p: f64, a: f64, b: f64) -> (f64, f64, usize) {
+    assert!(a > 0f64 && b > 0f64, "Bad a or b in routine invbetai");
+    if p.is_nan() {
+        return (f64::NAN, f64::NAN, 0);
+    } else if p <= 0f64 {
+        return (0f64, 0f64, 0);
+    } else if p >= 1f64 {
+        return (1f64, 0f64, 0);
+    }
+    // g is increasing in x for both tails, and cannot be resolved below the
+    // rounding of the smaller tail
+    let g = |x: f64| {
+        if p <= 0.5 {
+            betai(a, b, x) - p
+        } else {
+            (1f64 - p) - betaic(a, b, x)
+        }
+    };
+    let gtol = 2f64 * EPS * p.min(1f64 - p);
     let a1 = a - 1f64;
     let b1 = b - 1f64;
-    let mut t: f64;
-    let mut x: f64;
-    let mut u: f64;
-    if p <= 0f64 {
-        return 0f64;
-    } else if p >= 1f64 {
-        return 1f64;
-    } else if a >= 1f64 && b >= 1f64 {
+    let afac = -ln_beta(a, b);
+    // Bracket with the values of g at its ends
+    let (mut lo, mut glo) = (0f64, -p);
+    let (mut hi, mut ghi) = (1f64, 1f64 - p);
+    let mut x = invbetai_guess(p, a, b);
+    if !(x > lo && x < hi) {
+        x = invbetai_bisect(lo, hi);
+    }
+    // Steps of the last two iterations
+    let mut step1 = f64::INFINITY;
+    let mut step2 = f64::INFINITY;
+    let mut iter = 0;
+    while iter < 200 {
+        iter += 1;
+        let err = g(x);
+        if err.abs() <= gtol {
+            return (x, err, iter);
+        } else if err < 0f64 {
+            lo = x;
+            glo = err;
+        } else {
+            hi = x;
+            ghi = err;
+        }
+        // Halley step with the density as the derivative
+        let t = (a1 * x.ln() + b1 * (-x).ln_1p() + afac).exp();
+        let u = err / t;
+        let dx = u / (1f64 - 0.5 * 1f64.min(u * (a1 / x - b1 / (1f64 - x))));
+        let mut xnew = x - dx;
+        if dx.abs() <= EPS * x {
+            if xnew > lo && xnew < hi {
+                x = xnew;
+                break;
+            }
+            return (x, err, iter);
+        }
+        if !(xnew > lo && xnew < hi) {
+            // The step may overshoot a bracket end that is already closer to the root
+            let (end, gend) = if xnew <= lo { (lo, glo) } else { (hi, ghi) };
+            if (xnew - end).abs() <= 2f64 * EPS * end && gend.abs() < err.abs() {
+                return (end, gend, iter);
+            }
+            xnew = invbetai_bisect(lo, hi);
+        } else if 2f64 * dx.abs() > step2 {
+            // The steps stop shrinking, as deep in a tail where the Halley
+            // correction only creeps forward
+            xnew = invbetai_bisect(lo, hi);
+        }
+        step2 = step1;
+        step1 = (xnew - x).abs();
+        x = xnew;
+        // Stop once the bracket can no longer be split, including roots that underflow
+        if hi - lo <= EPS * lo || hi <= f64::MIN_POSITIVE || x == lo || x == hi {
+            return if glo.abs() <= ghi.abs() {
+                (lo, glo, iter)
+            } else {
+                (hi, ghi, iter)
+            };
+        }
+    }
+    (x, g(x), iter)
+}
+
+/// Initial guess for `invbetai` (Numerical Recipes)
+fn invbetai_guess(p: f64, a: f64, b: f64) -> f64 {
+    if a >= 1f64 && b >= 1f64 {
         let pp = if p < 0.5 { p } else { 1f64 - p };
-        t = (-2f64 * pp.ln()).sqrt();
-        x = (2.30753 + t * 0.27061) / (1f64 + t * (0.99229 + t * 0.04481)) - t;
+        let t = (-2f64 * pp.ln()).sqrt();
+        let mut x = (2.30753 + t * 0.27061) / (1f64 + t * (0.99229 + t * 0.04481)) - t;
         if p < 0.5 {
             x = -x;
         }
@@ -191,38 +701,211 @@ pub fn invbetai(p: f64, a: f64, b: f64) -> f64 {
         let w = (x * (al + h).sqrt() / h)
             - (1f64 / (2f64 * b - 1f64) - 1f64 / (2f64 * a - 1f64))
                 * (al + 5f64 / 6f64 - 2f64 / (3f64 * h));
-        x = a / (a + b * (2f64 * w).exp());
+        // Far in the tails, I_x(a,b) ≈ x^a / (a B(a,b)) and 1 - I_x(a,b) ≈ (1-x)^b / (b B(a,b))
+        let lnbeta = ln_beta(a, b);
+        if p < 0.5 {
+            let xt = (((a * p).ln() + lnbeta) / a).exp();
+            if (a + b) * xt < 0.1 * (a + 1f64) {
+                return xt;
+            }
+        } else {
+            let xt = (((b * (1f64 - p)).ln() + lnbeta) / b).exp();
+            if (a + b) * xt < 0.1 * (b + 1f64) {
+                return 1f64 - xt;
+            }
+        }
+        a / (a + b * (2f64 * w).exp())
     } else {
         let lna = (a / (a + b)).ln();
         let lnb = (b / (a + b)).ln();
-        t = (a * lna).exp() / a;
-        u = (b * lnb).exp() / b;
+        let t = (a * lna).exp() / a;
+        let u = (b * lnb).exp() / b;
         let w = t + u;
-        x = if p < t / w {
+        if p < t / w {
             (a * w * p).powf(1f64 / a)
         } else {
             1f64 - (b * w * (1f64 - p)).powf(1f64 / b)
-        };
+        }
     }
-    let afac = -ln_gamma(a) - ln_gamma(b) + ln_gamma(a + b);
-    for j in 0..10 {
-        if x == 0f64 || x == 1f64 {
-            return x;
+}
+
+/// Bisection point of the bracket (lo, hi), geometric in x or 1 - x while the bracket
+/// spans a wide range so that roots close to 0 or 1 are reached in a few steps
+fn invbetai_bisect(lo: f64, hi: f64) -> f64 {
+    if hi <= 0.5 {
+        let lo = lo.max(f64::MIN_POSITIVE);
+        if hi > 4f64 * lo {
+            return lo.sqrt() * hi.sqrt();
         }
-        let err = betai(a, b, x) - p;
-        t = (a1 * x.ln() + b1 * (1f64 - x).ln() + afac).exp();
-        u = err / t;
-        t = u / (1f64 - 0.5 * 1f64.min(u * (a1 / x - b1 / (1f64 - x))));
-        x -= t;
-        if x <= 0f64 {
-            x = 0.5 * (x + t);
+    } else if lo >= 0.5 {
+        let (clo, chi) = ((1f64 - hi).max(0.5 * EPS), 1f64 - lo);
+        if chi > 4f64 * clo {
+            return 1f64 - clo.sqrt() * chi.sqrt();
         }
-        if x >= 1f64 {
-            x = 0.5 * (x + t + 1f64);
+    } else if hi - lo > 0.5 {
+        return 0.5;
+    }
+    0.5 * (lo + hi)
+}
+
+/// Calculates the first shape parameter a for which I_x(a,b) = p.
+///
+/// This is the inverse of the regularized incomplete beta function with respect to
+/// `a`. Since I_x(a,b) decreases monotonically from 1 to 0 as `a` grows, the root is
+/// bracketed and then refined with safeguarded Newton steps.
+///
+/// # Arguments
+///
+/// * `p` - The probability value (between 0 and 1)
+/// * `b` - Second shape parameter
+/// * `x` - The upper limit of integration
+///
+/// # Returns
+///
+/// The value of a for which I_x(a,b) = p, or NaN if the iteration does not converge
+///
+/// # Panics
+///
+/// Panics if `b` ≤ 0 or `x` is not in the open interval (0,1).
+pub fn invbetai_a(p: f64, b: f64, x: f64) -> f64 {
+    assert!(b > 0f64, "Bad b in routine invbetai_a");
+    assert!(x > 0f64 && x < 1f64, "Bad x in routine invbetai_a");
+    if p.is_nan() {
+        return f64::NAN;
+    } else if p <= 0f64 {
+        return f64::INFINITY;
+    } else if p >= 1f64 {
+        return 0f64;
+    }
+    // I_x(a,b) is about 1/2 when the mean a/(a+b) is near x
+    let guess = b * x / (1f64 - x);
+    // Solve for the smaller tail in log space, so that p far below 1e-16 keeps its digits
+    if p <= 0.5 {
+        let lnp = p.ln();
+        invbeta_shape(
+            |a| betai(a, b, x).ln() - lnp,
+            |a| betai_derivative_a(a, b, x) / betai(a, b, x),
+            guess,
+        )
+    } else {
+        let lnq = (-p).ln_1p();
+        invbeta_shape(
+            |a| lnq - betaic(a, b, x).ln(),
+            |a| betai_derivative_a(a, b, x) / betaic(a, b, x),
+            guess,
+        )
+    }
+}
+
+/// Calculates the second shape parameter b for which I_x(a,b) = p.
+///
+/// This is the inverse of the regularized incomplete beta function with respect to
+/// `b`. Since I_x(a,b) increases monotonically from 0 to 1 as `b` grows, the root is
+/// bracketed and then refined with safeguarded Newton steps.
+///
+/// # Arguments
+///
+/// * `p` - The probability value (between 0 and 1)
+/// * `a` - First shape parameter
+/// * `x` - The upper limit of integration
+///
+/// # Returns
+///
+/// The value of b for which I_x(a,b) = p, or NaN if the iteration does not converge
+///
+/// # Panics
+///
+/// Panics if `a` ≤ 0 or `x` is not in the open interval (0,1).
+pub fn invbetai_b(p: f64, a: f64, x: f64) -> f64 {
+    assert!(a > 0f64, "Bad a in routine invbetai_b");
+    assert!(x > 0f64 && x < 1f64, "Bad x in routine invbetai_b");
+    if p.is_nan() {
+        return f64::NAN;
+    } else if p <= 0f64 {
+        return 0f64;
+    } else if p >= 1f64 {
+        return f64::INFINITY;
+    }
+    let guess = a * (1f64 - x) / x;
+    if p <= 0.5 {
+        let lnp = p.ln();
+        invbeta_shape(
+            |b| lnp - betai(a, b, x).ln(),
+            |b| -betai_derivative_b(a, b, x) / betai(a, b, x),
+            guess,
+        )
+    } else {
+        let lnq = (-p).ln_1p();
+        invbeta_shape(
+            |b| betaic(a, b, x).ln() - lnq,
+            |b| -betai_derivative_b(a, b, x) / betaic(a, b, x),
+            guess,
+        )
+    }
+}
+
+/// Finds the root of g, which decreases in the shape parameter s, starting from `guess`.
+/// `dg` is the derivative of g with respect to s. Returns NaN if the iteration does not converge.
+fn invbeta_shape<G: Fn(f64) -> f64, D: Fn(f64) -> f64>(g: G, dg: D, guess: f64) -> f64 {
+    // Bracket the root by halving and doubling
+    let mut lo = guess.max(FPMIN);
+    let mut hi = lo;
+    let mut glo = g(lo);
+    let mut ghi = glo;
+    while glo < 0f64 {
+        hi = lo;
+        ghi = glo;
+        lo *= 0.5;
+        if lo < f64::MIN_POSITIVE {
+            return 0f64;
         }
-        if t.abs() < EPS * x && j > 0 {
-            break;
+        glo = g(lo);
+    }
+    while ghi > 0f64 {
+        lo = hi;
+        glo = ghi;
+        hi *= 2f64;
+        if hi.is_infinite() {
+            return f64::INFINITY;
+        }
+        ghi = g(hi);
+    }
+    if glo == 0f64 {
+        return lo;
+    } else if ghi == 0f64 {
+        return hi;
+    }
+
+    // Newton steps in u = ln(s), falling back to bisection when a step leaves the
+    // bracket or does not at least halve the step before last (Numerical Recipes' rtsafe)
+    let (mut ulo, mut uhi) = (lo.ln(), hi.ln());
+    let mut u = 0.5 * (ulo + uhi);
+    let mut du_old = uhi - ulo;
+    let mut du = du_old;
+    for _ in 0..100 {
+        let s = u.exp();
+        let gs = g(s);
+        if gs == 0f64 {
+            return s;
+        } else if gs > 0f64 {
+            ulo = u;
+        } else {
+            uhi = u;
+        }
+        // dg/du = s dg/ds
+        let dgu = s * dg(s);
+        let unew = u - gs / dgu;
+        let newton =
+            dgu < 0f64 && unew > ulo && unew < uhi && (2f64 * gs).abs() <= (du_old * dgu).abs();
+        du_old = du;
+        let unew = if newton { unew } else { 0.5 * (ulo + uhi) };
+        du = unew - u;
+        u = unew;
+        if du.abs() <= 2f64 * EPS * u.abs().max(1f64)
+            || uhi - ulo <= 2f64 * EPS * uhi.abs().max(1f64)
+        {
+            return u.exp();
         }
     }
-    x
+    f64::NAN
 }
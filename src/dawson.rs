@@ -3,55 +3,956 @@
 //! It includes the following main function:
 //! - `dawson`: Calculates Dawson's integral for real values.
 
-use crate::utils::sign;
-use std::f64::consts::PI;
+use crate::{INV_SQRT_PI, SQRT_PI_2};
+use std::f64::consts::FRAC_2_SQRT_PI;
 
 /// Dawson's integral for real values.
-/// Accurate to about 2e-7 for small inputs.
+///
+/// ### Definition:
+/// D(x) = exp(-x^2) ∫_0^x exp(t^2) dt
+///
+/// ### Notes on Implementation:
+/// This computes sqrt(pi)/2 * ℑ(w(x)) with the method of `im_w_of_x` from `libcerf`,
+/// and is accurate to about 1e-15 relative error over the whole real line.
 pub fn dawson(x: f64) -> f64 {
-    const NMAX: usize = 6;
-    let mut c = [0_f64; NMAX];
-    let h = 0.4;
-    let a1 = 2. / 3.;
-    let a2 = 0.4;
-    let a3 = 2. / 7.;
-    let n0: u64; // i is declared in the loops.
-    let mut d1: f64;
-    let mut d2: f64;
-    let mut e1: f64;
-    let e2: f64;
-    let mut sum: f64;
-    let x2: f64;
-    let xp: f64;
-    let xx: f64;
-    let ans: f64;
+    SQRT_PI_2 * im_w_of_x(x)
+}
 
-    for i in 0..NMAX {
-        c[i] = f64::exp(-((2. * i as f64 + 1.) * h).powf(2.));
+/// Computes the scaled Dawson integral of x,
+/// which is 2 * Dawson(x) / sqrt(pi).
+/// This equals the imaginary part of the Faddeeva
+/// function of x, for real x.
+///
+/// It uses a continued-fraction expansion for |x| > 45,
+/// a Taylor series for |x| <= 100/97 - 1, and piecewise
+/// Chebyshev polynomials in y100 = 100 / (1 + |x|) in between.
+pub(crate) fn im_w_of_x(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
     }
-
-    if x.abs() < 0.2 {
-        x2 = x * x;
-        ans = x * (1.0 - a1 * x2 * (1.0 - a2 * x2 * (1.0 - a3 * x2)));
-    } else {
-        xx = x.abs();
-        n0 = 2 * (0.5 * xx / h + 0.5) as u64; // Simply truncates the decimal places, as in NR.
-        xp = xx - n0 as f64 * h;
-        e1 = f64::exp(2.0 * xp * h);
-        e2 = e1 * e1;
-        d1 = n0 as f64 + 1.;
-        d2 = d1 - 2.0;
-        sum = 0.0;
-
-        for i in 0..NMAX {
-            d1 += 2.;
-            d2 -= 2.;
-            e1 *= e2;
-
-            sum += c[i] * (e1 / d1 + 1.0 / (d2 * e1));
+    let ax = x.abs();
+    let res = if ax > 45. {
+        // Continued-fraction expansion is faster
+        if ax > 5e7 {
+            // 1-term expansion, important to avoid overflow
+            INV_SQRT_PI / ax
+        } else {
+            // 5-term expansion, simplified from:
+            //   1/sqrt(pi) / (x - 0.5/(x - 1/(x - 1.5/(x - 2/x))))
+            let x2 = ax * ax;
+            INV_SQRT_PI * (x2 * (x2 - 4.5) + 2.) / (ax * (x2 * (x2 - 5.) + 3.75))
         }
+    } else {
+        w_im_y100(100. / (1. + ax), ax)
+    };
+    if x < 0. {
+        -res
+    } else {
+        res
+    }
+}
 
-        ans = (1. / PI.sqrt()) * sign(f64::exp(-xp * xp), x) * sum;
+/// ℑ(w(x)) for 0 <= x <= 45, given y100 = 100 / (1 + x).
+fn w_im_y100(y100: f64, x: f64) -> f64 {
+    let j = y100 as usize;
+    if j >= N_CHEB {
+        // Taylor expansion for small x:
+        //   2/sqrt(pi) * (x - 2/3 x^3 + 4/15 x^5 - 8/105 x^7 + 16/945 x^9)
+        let x2 = x * x;
+        return x
+            * (FRAC_2_SQRT_PI
+                - x2 * (0.7522527780636751
+                    - x2 * (0.30090111122547003
+                        - x2 * (0.08597174606442 - x2 * 0.01910483245876))));
     }
-    return ans;
+    // Polynomial in t on the subrange j <= y100 < j + 1. Computing
+    // t = 2 y100 - (2j + 1) from x rather than y100 avoids the rounding of y100.
+    let k = (2 * j + 1) as f64;
+    let t = (-k).mul_add(x, 200. - k) / (1. + x);
+    let c = &W_IM_CHEBYSHEV_COEFFS[N_TERMS * j..N_TERMS * (j + 1)];
+    c.iter().rev().fold(0., |acc, &cj| acc * t + cj)
 }
+
+/// Number of unit subranges of y100 covered by `W_IM_CHEBYSHEV_COEFFS`.
+const N_CHEB: usize = 97;
+/// Number of coefficients per subrange.
+const N_TERMS: usize = 9;
+
+/// Coefficients of the degree-8 polynomials in t = 2 y100 - (2j + 1)
+/// interpolating ℑ(w(x)) at Chebyshev nodes on each subrange j <= y100 < j + 1,
+/// lowest order first. Generated by scripts/dawson_coeffs.py.
+const W_IM_CHEBYSHEV_COEFFS: [f64; N_CHEB * N_TERMS] = [
+    0.002835159332882219,
+    0.0028494783221377253,
+    1.4427470563273595e-05,
+    1.0939723171973116e-07,
+    9.24743095130506e-10,
+    8.911055936074208e-12,
+    9.294900552883859e-14,
+    1.0484746123271284e-15,
+    1.2558202345461395e-17,
+    0.008592716124394035,
+    0.0029085312941639946,
+    1.510678370772162e-05,
+    1.1716710090054356e-07,
+    1.0197388014108447e-09,
+    1.012044840249118e-11,
+    1.0914310280688726e-13,
+    1.2745489028135858e-15,
+    1.5846986281365274e-17,
+    0.014471159831187703,
+    0.002970397897026213,
+    1.5835096760167983e-05,
+    1.2574803519806035e-07,
+    1.1278672411866905e-09,
+    1.1544730163879276e-11,
+    1.2890497759398466e-13,
+    1.5612208289267214e-15,
+    2.0187877337548498e-17,
+    0.02047632042032461,
+    0.0030352843012896556,
+    1.661760938699723e-05,
+    1.3525429879897097e-07,
+    1.2515095877366516e-09,
+    1.3232312863964456e-11,
+    1.5321397291028788e-13,
+    1.92838836285215e-15,
+    2.5988764433712343e-17,
+    0.026614461952489003,
+    0.003103418927623232,
+    1.7460268109977754e-05,
+    1.4582131034826253e-07,
+    1.3935959506909857e-09,
+    1.5245231262149762e-11,
+    1.8337972279775513e-13,
+    2.4038919127462518e-15,
+    3.384801197112635e-17,
+    0.032892330248093585,
+    0.003175055706797507,
+    1.8369907582297507e-05,
+    1.5761063702089456e-07,
+    1.5577638788747675e-09,
+    1.7663868462715497e-11,
+    2.2117800411866967e-13,
+    3.0273474093550165e-15,
+    4.4661345267306417e-17,
+    0.039317207681134336,
+    0.0032504779701937538,
+    1.935442604649845e-05,
+    1.7081646971321288e-07,
+    1.7485734706837831e-09,
+    2.0593687304952782e-11,
+    2.690544178084015e-13,
+    3.856212366272003e-15,
+    5.980083243075891e-17,
+    0.04589697651136774,
+    0.0033300031273110976,
+    2.0423005398018682e-05,
+    1.8567412470376466e-07,
+    1.9718039381394205e-09,
+    2.4175006536835817e-11,
+    3.304369786676539e-13,
+    4.975657396834316e-15,
+    8.142463221970572e-17,
+    0.052640192524848965,
+    0.0034139883358846723,
+    2.158639024057508e-05,
+    2.0247136501568894e-07,
+    2.2348698361124586e-09,
+    2.85975163021228e-11,
+    4.1022895278122064e-13,
+    6.515161357874882e-15,
+    1.130342128529331e-16,
+    0.05955617122865677,
+    0.0035028374386648913,
+    2.2857246150958433e-05,
+    2.2156372146525183e-07,
+    2.547417359744945e-09,
+    3.412239089087205e-11,
+    5.15610849856829e-13,
+    8.677507570659734e-15,
+    1.605244742904539e-16,
+    0.06665508948510822,
+    0.0035970095381271288,
+    2.4250626164260104e-05,
+    2.4339561521785036e-07,
+    2.922199333501778e-09,
+    4.1117013528121763e-11,
+    6.573959472793226e-13,
+    1.1791885649462924e-14,
+    2.3427994037673235e-16,
+    0.07394810634551918,
+    0.003697029721656934,
+    2.5784588137224448e-05,
+    2.6853012002366726e-07,
+    3.3763963282228968e-09,
+    5.0111549981875e-11,
+    8.524312113833237e-13,
+    1.641707963723366e-14,
+    3.5368179932405865e-16,
+    0.08144750806500296,
+    0.0038035026606492704,
+    2.748102757209239e-05,
+    2.9769200731832254e-07,
+    3.933682326067607e-09,
+    6.18954711336407e-11,
+    1.1281146064344332e-12,
+    2.3558531294361123e-14,
+    5.578574472476749e-16,
+    0.08916688402758273,
+    0.003917130132243894,
+    2.9366827260188273e-05,
+    3.318320439035047e-07,
+    4.6276017983551784e-09,
+    7.769263138325457e-11,
+    1.5316430211554233e-12,
+    3.518310050532799e-14,
+    9.361523702139515e-16,
+    0.09712134288803234,
+    0.004038734035320791,
+    3.1475490395515326e-05,
+    3.72227142271243e-07,
+    5.507439495111545e-09,
+    9.950917530078359e-11,
+    2.1517809756422998e-12,
+    5.572864183034762e-14,
+    1.7418000599342327e-15,
+    0.10532778218603314,
+    0.004169287361406538,
+    3.3849549773950615e-05,
+    4.206459619369206e-07,
+    6.649462663984438e-09,
+    1.3094103583068672e-10,
+    3.1821079850576762e-12,
+    9.727196769996069e-14,
+    3.7553781034830176e-15,
+    0.11380523107427108,
+    0.0043099572287871825,
+    3.6544324341565806e-05,
+    4.796504402858845e-07,
+    8.181903424453456e-09,
+    1.7934133226378616e-10,
+    5.095665643389226e-12,
+    1.8850494845423721e-13,
+    7.970268611788671e-15,
+    0.12257529703447467,
+    0.004462167571002699,
+    3.96343047212894e-05,
+    5.532155376988801e-07,
+    1.0343619444031366e-08,
+    2.603383014122158e-10,
+    8.774359482458112e-12,
+    3.442710914201565e-13,
+    1.0217652805334905e-14,
+    0.13166276955656694,
+    0.0046276970481783,
+    4.322502638169924e-05,
+    6.47991640200142e-07,
+    1.3580076780487707e-08,
+    3.9839800859376625e-10,
+    1.4440765164443192e-11,
+    4.2193454211551696e-13,
+    -4.811378265697983e-15,
+    0.14109647869803357,
+    0.0048088424418545345,
+    4.7474504753351935e-05,
+    7.750986646868886e-07,
+    1.853685157185493e-08,
+    6.014662332886982e-10,
+    1.853397670404926e-11,
+    4.1033440372125457e-14,
+    -4.615983498720428e-14,
+    0.15091057940548935,
+    0.0050086864672004685,
+    5.262248283220169e-05,
+    9.503466472200783e-07,
+    2.5614261283852977e-08,
+    8.018319678194612e-10,
+    1.2282600412400939e-11,
+    -1.0531777834561312e-12,
+    -8.619501057184286e-14,
+    0.1611464811601701,
+    0.005231466158165537,
+    5.900553454592075e-05,
+    1.1885518333916628e-06,
+    3.3975801381124975e-08,
+    8.211154711930198e-10,
+    -1.235757464161129e-11,
+    -2.435511084103945e-12,
+    -7.520519176089752e-14,
+    0.17185551279680453,
+    0.005482900296759942,
+    6.701322665835825e-05,
+    1.4897400671429873e-06,
+    4.069028581625896e-08,
+    4.406087281778775e-10,
+    -5.264491204834696e-11,
+    -3.094058239693314e-12,
+    1.5193084517297267e-15,
+    0.18310194559815257,
+    0.0057701559375966955,
+    7.694878940172741e-05,
+    1.8227569842294766e-06,
+    4.1092208383279234e-08,
+    -4.4009500044564414e-10,
+    -9.219547690826147e-11,
+    -2.2657385199123436e-12,
+    1.0007895877229307e-13,
+    0.1949652719154663,
+    0.0061010853144364725,
+    8.881288105632863e-05,
+    2.1180686746360618e-06,
+    3.06521456220165e-08,
+    -1.6841328574821505e-09,
+    -1.1008140161816988e-10,
+    -1.2180790113748112e-13,
+    1.5708676012779758e-13,
+    0.20754006813966575,
+    0.0064825787724922075,
+    0.0001020959962752129,
+    2.2785233392555057e-06,
+    7.349522496071842e-09,
+    -2.944270596906204e-09,
+    -9.40826851610596e-11,
+    2.3609987492826024e-12,
+    1.414599488416965e-13,
+    0.2209318555484517,
+    0.006918287815018797,
+    0.00011568723331156076,
+    2.2060577946320496e-06,
+    -2.6929730666405924e-08,
+    -3.817650614609723e-09,
+    -4.739952458971087e-11,
+    4.095369660716276e-12,
+    6.91680949875146e-14,
+    0.23524827304057813,
+    0.007406335076200874,
+    0.00012796333874616196,
+    1.8327267316169086e-06,
+    -6.674291075821924e-08,
+    -4.020474097156153e-09,
+    1.4516016556177241e-11,
+    4.492160670595947e-12,
+    -1.8599548699173346e-14,
+    0.25058626331812744,
+    0.007937728515160206,
+    0.00013704268650418174,
+    1.1427511739544326e-06,
+    -1.0485442451247887e-07,
+    -3.485036475575993e-09,
+    7.265650949957204e-11,
+    3.619545977511251e-12,
+    -8.490997025525417e-14,
+    0.2670172490028069,
+    0.008495993611962587,
+    0.0001411235944393952,
+    1.780042728860427e-07,
+    -1.3443492110817688e-07,
+    -2.35124563171497e-09,
+    1.1245851343886205e-10,
+    1.985050217565096e-12,
+    -1.1287205681591057e-13,
+    0.28457293586253657,
+    0.009058156389265043,
+    0.00013880520331141036,
+    -9.726230236251173e-07,
+    -1.5077100042196349e-07,
+    -8.85743174869048e-10,
+    1.2760314233056843e-10,
+    2.0155163776687386e-13,
+    -1.0515723074020087e-13,
+    0.30323425595617387,
+    0.009596834679059743,
+    0.00012931067776726003,
+    -2.1938741702794586e-06,
+    -1.520288858550751e-07,
+    6.178835052192507e-10,
+    1.1957836703057617e-10,
+    -1.2598178737447207e-12,
+    -7.515661866993906e-14,
+    0.32292521181517386,
+    0.010082957727001199,
+    0.00011257589426154887,
+    -3.3670890319327294e-06,
+    -1.3910529039625733e-07,
+    1.9170714371877353e-09,
+    9.484021632605888e-11,
+    -2.1650017683307828e-12,
+    -3.7872186095794117e-14,
+    0.3435123355791175,
+    0.010488575435572745,
+    8.92094441983642e-05,
+    -4.389345957648313e-06,
+    -1.1488596388185454e-07,
+    2.8599494116693066e-09,
+    6.154646656058824e-11,
+    -2.4935748982031673e-12,
+    -4.461880446936801e-15,
+    0.3648094664214367,
+    0.010789304203431861,
+    6.0357993745281164e-05,
+    -5.185586217413071e-06,
+    -8.329166407771799e-08,
+    3.38980111786689e-09,
+    2.7082932873621974e-11,
+    -2.3603379446949127e-12,
+    1.9335744898364807e-14,
+    0.3865867993569494,
+    0.010966119158288806,
+    2.7521612041847955e-05,
+    -5.713277453767113e-06,
+    -4.84047727911697e-08,
+    3.5268354132825267e-09,
+    -3.2383606261921764e-12,
+    -1.9334203115186195e-12,
+    3.233962025895086e-14,
+    0.40858275583808706,
+    0.011006378016848466,
+    -7.639637668522436e-06,
+    -5.9609835484246e-06,
+    -1.3834610028318923e-08,
+    3.3406952975276357e-09,
+    -2.647492483878413e-11,
+    -1.3750229507459429e-12,
+    3.617379917399596e-14,
+    0.43051714914006683,
+    0.010904106549500817,
+    -4.347752725678783e-05,
+    -5.942973954779853e-06,
+    1.7639200197150112e-08,
+    2.9235991690001425e-09,
+    -4.1718796109619e-11,
+    -8.102333979949846e-13,
+    3.362136268638034e-14,
+    0.45210428135559605,
+    0.0106596707563844,
+    -7.84886399132572e-05,
+    -5.691986088621486e-06,
+    4.418185046856321e-08,
+    2.3694306174569784e-09,
+    -4.949262333369188e-11,
+    -3.1827277182252634e-13,
+    2.7495307309052247e-14,
+    0.47306491195005224,
+    0.010279006119745977,
+    -0.00011140268171830473,
+    -5.251803524745151e-06,
+    6.484689815865306e-08,
+    1.760362483794573e-09,
+    -5.112948121407859e-11,
+    6.267457593134587e-14,
+    2.0055288988860253e-14,
+    0.4931363896571986,
+    0.009772579911477201,
+    -0.00014122854267291515,
+    -4.6707252568834996e-06,
+    7.942134797835092e-08,
+    1.160302718440411e-09,
+    -4.8269604294997315e-11,
+    3.2477250976945643e-13,
+    1.2830277996666264e-14,
+    0.51208057433416,
+    0.009154242235400923,
+    -0.00016726530230228622,
+    -3.996462175252766e-06,
+    8.82322529019592e-08,
+    6.134311336507336e-10,
+    -4.2516753596826605e-11,
+    4.79104371068362e-13,
+    6.677431009250194e-15,
+    0.5296894545860749,
+    0.00844008804451168,
+    -0.00018908729783902807,
+    -3.272590546778294e-06,
+    9.195619301611616e-08,
+    1.4593989152184313e-10,
+    -3.5243374630210454e-11,
+    5.46138300210848e-13,
+    1.941971345324964e-15,
+    0.5457885745433007,
+    0.007647415519588029,
+    -0.0002065123059077404,
+    -2.5364339140543113e-06,
+    9.14553662907047e-08,
+    -2.3061359005655538e-10,
+    -2.75101945359103e-11,
+    5.489580621688448e-13,
+    -1.3670445828526238e-15,
+    0.5602385191029849,
+    0.00679383217399972,
+    -0.0002195606661324572,
+    -1.8181127670443242e-06,
+    8.765033079091057e-08,
+    -5.154806205084472e-10,
+    -2.0061606964425424e-11,
+    5.091265518511929e-13,
+    -3.4276046954920093e-15,
+    0.5729347805745572,
+    0.005896532101039405,
+    -0.00022841145229164167,
+    -1.1404605562013423e-06,
+    8.143028537188558e-08,
+    -7.151244724313503e-10,
+    -1.3363672229780222e-11,
+    4.446149854187017e-13,
+    -4.496348928367315e-15,
+    0.5838063544840782,
+    0.004971746953084283,
+    -0.0002333600153988866,
+    -5.19520644486087e-07,
+    7.35965717661614e-08,
+    -8.402091676339478e-10,
+    -7.660418470419577e-12,
+    3.6914462984094733e-13,
+    -4.839400033121648e-15,
+    0.5928134023776949,
+    0.004034359206937973,
+    -0.00023477963738540968,
+    3.4615944987791326e-08,
+    6.483279738049046e-08,
+    -9.032916358782762e-10,
+    -3.0328053914889747e-12,
+    2.9237386761916126e-13,
+    -4.6943242523245484e-15,
+    0.5999442874311427,
+    0.0030976579788271744,
+    -0.00023308875765593737,
+    5.168168102384703e-07,
+    5.569458894769242e-08,
+    -9.171911731345191e-10,
+    5.48335046552942e-13,
+    2.205082941344215e-13,
+    -4.253805017328949e-15,
+    0.6052122447181987,
+    0.0021732138012345455,
+    -0.00022872428969534427,
+    9.258895992265346e-07,
+    4.661266122801591e-08,
+    -8.939372251453754e-10,
+    3.1791806608032185e-12,
+    1.5705458890274284e-13,
+    -3.662812653883388e-15,
+    0.6086518996979112,
+    0.0012708480848877453,
+    -0.0002221209011145927,
+    1.2636236031532797e-06,
+    3.7904033321361005e-08,
+    -8.441708996816408e-10,
+    4.9903642782501685e-12,
+    1.0355439471933743e-13,
+    -3.023097811142158e-15,
+    0.610315801034992,
+    0.0003986743605586104,
+    -0.00021369573439519848,
+    1.533940212902618e-06,
+    2.9787476205578635e-08,
+    -7.768779291416715e-10,
+    6.12404698263634e-12,
+    6.021669148511397e-14,
+    -2.400854175146746e-15,
+    0.6102710904787984,
+    -0.0004368090450805988,
+    -0.0002038378378825802,
+    1.7421743090883442e-06,
+    2.2400423278390204e-08,
+    -6.993471932009982e-10,
+    6.7189460229495625e-12,
+    2.6419960356417606e-14,
+    -1.835028915430013e-15,
+    0.6085963948921743,
+    -0.0012305921390964125,
+    -0.0001929015025386106,
+    1.894490466397921e-06,
+    1.581552871745477e-08,
+    -6.172704059894955e-10,
+    6.9014787619954944e-12,
+    1.0858170071250378e-15,
+    -1.3449313023346673e-15,
+    0.6053789942648607,
+    -0.001979006224139571,
+    -0.00018120271393023648,
+    1.9974264162313254e-06,
+    1.0055793923604733e-08,
+    -5.349199791953924e-10,
+    6.781328139140465e-12,
+    -1.7059206849213267e-14,
+    -9.365549228310501e-16,
+    0.6007122945790411,
+    -0.0026795676776166354,
+    -0.00016901799553612322,
+    2.057549832433262e-06,
+    5.107715748091594e-09,
+    -4.553607982802906e-10,
+    6.4500155187365305e-12,
+    -2.931167775413539e-14,
+    -6.074834270328987e-16,
+    0.5946936152011272,
+    -0.0033308208190600992,
+    -0.00015658501295903642,
+    2.0812116912895415e-06,
+    9.32274249494083e-10,
+    -3.806667374005438e-10,
+    5.981380015213978e-12,
+    -3.6887077548847605e-14,
+    -3.504896730131912e-16,
+    0.5874222863177538,
+    -0.0039321858196059226,
+    -0.00014410441141446222,
+    2.074379001840402e-06,
+    -2.5261905761742595e-09,
+    -3.1212416519515677e-10,
+    5.4331543295076216e-12,
+    -4.0864152572621225e-14,
+    -1.560416805892848e-16,
+    0.5789980420003302,
+    -0.004483815700561891,
+    -0.0001317424596650109,
+    2.042530688829437e-06,
+    -5.333029619828401e-09,
+    -2.504128943564458e-10,
+    4.849071626091382e-12,
+    -4.2162206428737036e-14,
+    -1.3952850849923237e-17,
+    0.5695196879693125,
+    -0.004986464948807487,
+    -0.00011963416583479707,
+    1.990602178099103e-06,
+    -7.558013922938895e-09,
+    -1.9576060961806112e-10,
+    4.261129985702143e-12,
+    -4.153944393376689e-14,
+    8.560345227773668e-17,
+    0.5590840193006392,
+    -0.005441371103682688,
+    -0.00010788661102515703,
+    1.922966332298284e-06,
+    -9.27147293009954e-09,
+    -1.4807038677155082e-10,
+    3.691783970124061e-12,
+    -3.9603726958652424e-14,
+    1.5152996686262184e-16,
+    0.5477849615292567,
+    -0.00585014979332134,
+    -9.658231431790312e-05,
+    1.843440523506927e-06,
+    -1.0541580014883545e-08,
+    -1.0702303407778707e-10,
+    3.155934464872839e-12,
+    -3.682974808236746e-14,
+    1.915465459282157e-16,
+    0.5357129083168283,
+    -0.006214703067076079,
+    -8.578249791716479e-05,
+    1.7553116363443467e-06,
+    -1.1432547084657176e-08,
+    -7.215709136842876e-11,
+    2.662656907020518e-12,
+    -3.357866075780051e-14,
+    2.121269332422075e-16,
+    0.5229542296204843,
+    -0.006537140436777632,
+    -7.553016494152798e-05,
+    1.6613725797181281e-06,
+    -1.2003521023407668e-08,
+    -4.292975368993525e-11,
+    2.2166523881730346e-12,
+    -3.011769711208486e-14,
+    2.1855289109646217e-16,
+    0.5095909257757789,
+    -0.0068197117603118595,
+    -6.585293619900737e-05,
+    1.563965411390671e-06,
+    -1.2308007722267273e-08,
+    -1.8761997535603487e-11,
+    1.8194328292274205e-12,
+    -2.6638356058936338e-14,
+    2.1503160886128692e-16,
+    0.4957004048182317,
+    -0.0070647509397614395,
+    -5.67656177290138e-05,
+    1.465027444914145e-06,
+    -1.2393681215933045e-08,
+    9.290435178996814e-13,
+    1.4702659144744644e-12,
+    -2.3272455410384566e-14,
+    2.0484067770212166e-16,
+    0.4813553625093524,
+    -0.007274629332740236,
+    -4.827248949577765e-05,
+    1.3661377309113945e-06,
+    -1.2302464209501922e-08,
+    1.670776002754546e-11,
+    1.1669118762082687e-12,
+    -2.010580073147161e-14,
+    1.9047822400925686e-16,
+    0.4666237467551144,
+    -0.0074517177649528485,
+    -4.036931874432258e-05,
+    1.2685621118898538e-06,
+    -1.2070791246058842e-08,
+    2.9105507892358136e-11,
+    9.061855362671153e-13,
+    -1.7189503206470942e-14,
+    1.7380509669433885e-16,
+    0.4515687903016827,
+    -0.007598356065003382,
+    -3.3045110380744183e-05,
+    1.1732956732035036e-06,
+    -1.172998675194287e-08,
+    3.861190570520226e-11,
+    6.843753384668054e-13,
+    -1.4549134921277607e-14,
+    1.561722720338925e-16,
+    0.436249097693309,
+    -0.007716829104030955,
+    -2.628361232137454e-05,
+    1.0811018836893549e-06,
+    -1.1306707390576386e-08,
+    4.5670446789304324e-11,
+    4.975478640055266e-13,
+    -1.2191984486378673e-14,
+    1.385306933840558e-16,
+    0.42071877443548483,
+    -0.007809348401505273,
+    -2.0064596897255316e-05,
+    9.925480668067187e-07,
+    -1.0823411936981319e-08,
+    5.067720332728742e-11,
+    3.4176242985690647e-13,
+    -1.0112698843740121e-14,
+    1.215232525758529e-16,
+    0.4050275880971084,
+    -0.007878038446087294,
+    -1.4364940764559243e-05,
+    9.080370922826531e-07,
+    -1.0298832715065078e-08,
+    5.3981671220264916e-11,
+    2.1321639408673916e-13,
+    -8.297589298839447e-15,
+    1.0556003975888284e-16,
+    0.38922115269731444,
+    -0.007924926970824206,
+    -9.159525879933416e-06,
+    8.278353510221761e-07,
+    -9.748430992363969e-09,
+    5.588902904142282e-11,
+    1.0833805624128508e-13,
+    -6.727855350091091e-15,
+    9.087870186799487e-17,
+    0.37334112915460305,
+    -0.007951938510922315,
+    -4.421983354903445e-06,
+    7.520971903824028e-07,
+    -9.184825048864576e-09,
+    5.666326666864261e-11,
+    2.3840709700987014e-14,
+    -5.381947870544502e-15,
+    7.759201505983269e-17,
+    0.35742543583374226,
+    -0.007960890657152796,
+    -1.2530071052618878e-07,
+    6.808860574490064e-07,
+    -8.618184326929115e-09,
+    5.6530784202031896e-11,
+    -4.325146136452104e-14,
+    -4.237259284497098e-15,
+    6.572477199076496e-17,
+    0.3415084643197962,
+    -0.00795349249687738,
+    3.7576885610753414e-06,
+    6.141926363309043e-07,
+    -8.056586471943234e-09,
+    5.568417525079912e-11,
+    -9.559734401472747e-14,
+    -3.2712958652141245e-15,
+    5.524193053306864e-17,
+    0.32562129649136345,
+    -0.007931344806794619,
+    7.253915993343013e-06,
+    5.519502808195045e-07,
+    -7.506336475969863e-09,
+    5.428599605095427e-11,
+    -1.3554638249353682e-13,
+    -2.4624578878843663e-15,
+    4.6069744250038903e-17,
+    0.3097919197707839,
+    -0.007895941626420537,
+    1.0389774377667684e-05,
+    4.940480430652512e-07,
+    -6.972248775301508e-09,
+    5.247238807967982e-11,
+    -1.651548295102952e-13,
+    -1.7905278369712107e-15,
+    3.811134515151739e-17,
+    0.2940454381121446,
+    -0.00784867289903628,
+    1.3190885683099177e-05,
+    4.403415875315519e-07,
+    -6.457894217084355e-09,
+    5.035647115234047e-11,
+    -1.8620725116445924e-13,
+    -1.2369453160100612e-15,
+    3.125800449528502e-17,
+    0.2784042768625366,
+    -0.007790827917625189,
+    1.56819287987022e-05,
+    3.906622613641842e-07,
+    -5.9658144503197705e-09,
+    4.8031460047452725e-11,
+    -2.0024074582854164e-13,
+    -7.849295229350486e-16,
+    2.539707310586645e-17,
+    0.262888380111638,
+    -0.007723599357611901,
+    1.7886516796193558e-05,
+    3.4482457036766656e-07,
+    -5.497706629673664e-09,
+    4.557348349643809e-11,
+    -2.0857008449184924e-13,
+    -4.1949574307826664e-16,
+    2.041740587083876e-17,
+    0.2475153995418103,
+    -0.007648087716529023,
+    1.9827114835029925e-05,
+    3.026322860882877e-07,
+    -5.054581436745223e-09,
+    4.3044102325551635e-11,
+    -2.1231254650193903e-13,
+    -1.274008152523623e-16,
+    1.6212907622333517e-17,
+    0.23230087411688916,
+    -0.007565306013638415,
+    2.1524991113016845e-05,
+    2.6388338551548064e-07,
+    -4.636897391110811e-09,
+    4.0492535584371344e-11,
+    -2.124116478511024e-13,
+    1.0295652002675571e-16,
+    1.2684698428115509e-17,
+    0.2172584002129734,
+    -0.007476184630598004,
+    2.300019440412706e-05,
+    2.2837400160288317e-07,
+    -4.244674293663724e-09,
+    3.7957611163938785e-11,
+    -2.096592703650058e-13,
+    2.8166150962415644e-16,
+    9.742282762507255e-18,
+    0.2023997920078819,
+    -0.007381576198049392,
+    2.4271552727630028e-05,
+    1.9590154079914425e-07,
+    -3.8775884551156145e-09,
+    3.54694618837245e-11,
+    -2.0471592485342935e-13,
+    4.174220283992594e-16,
+    7.304015804071533e-18,
+    0.187735232115581,
+    -0.0072822604530340404,
+    2.535668856783997e-05,
+    1.662671034304394e-07,
+    -3.5350521401796997e-09,
+    3.305099022055892e-11,
+    -1.9812903997221383e-13,
+    5.17710576463108e-16,
+    5.297087793987895e-18,
+    0.1732734125847965,
+    -0.00717894900891434,
+    2.6272046822382907e-05,
+    1.3927732427186406e-07,
+    -3.2162794221242344e-09,
+    3.071912545529243e-11,
+    -1.9034927747523991e-13,
+    5.889033014875124e-16,
+    3.6571907465706795e-18,
+    0.15902166648328672,
+    -0.00707228999342462,
+    2.7032932310131645e-05,
+    1.1474573403502597e-07,
+    -2.9203404062657016e-09,
+    2.8485896541737145e-11,
+    -1.817449464436846e-13,
+    6.364119771465953e-16,
+    2.3279879240811196e-18,
+    0.14498609036610283,
+    -0.006962872522004576,
+    2.765355422916028e-05,
+    9.249372774909836e-08,
+    -2.646205553292351e-09,
+    2.635934283984948e-11,
+    -1.7261463383933258e-13,
+    6.648065143834628e-16,
+    1.2604728560791355e-18,
+    0.1311716579820805,
+    -0.006851230983028183,
+    2.814707543113376e-05,
+    7.235121303116539e-08,
+    -2.392781619516534e-09,
+    2.434428327945542e-11,
+    -1.6319819390950217e-13,
+    6.779267572356031e-16,
+    4.122791784996519e-19,
+    0.11758232561160627,
+    -0.006737849119246414,
+    2.8525664781722972e-05,
+    5.415699990415738e-08,
+    -2.158940534327845e-09,
+    2.244296273096742e-11,
+    -1.5368625031651324e-13,
+    6.7898313497969745e-16,
+    -2.5301656236953864e-19,
+    0.10422112945361674,
+    -0.006623163895984632,
+    2.880055121636411e-05,
+    3.77589839847677e-08,
+    -1.943542356662615e-09,
+    2.065559249439954e-11,
+    -1.4422836668425266e-13,
+    6.706462975788118e-16,
+    -7.665711748928652e-19,
+    0.09109027549354108,
+    -0.006507569151611587,
+    2.8982078385527515e-05,
+    2.30141663808786e-08,
+    -1.745453292469116e-09,
+    1.8980799971714648e-11,
+    -1.3494003674406696e-13,
+    6.551262215700009e-16,
+    -1.1549125604861221e-18,
+    0.07819122228877137,
+    -0.006391419029730467,
+    2.9079759021300043e-05,
+    9.788546360903044e-09,
+    -1.5635596134139117e-09,
+    1.7416000821315354e-11,
+    -1.2590863676054184e-13,
+    6.342414920254655e-16,
+    -1.4405287978674548e-18,
+    0.0655247571061474,
+    -0.006275031195608311,
+    2.910232835432386e-05,
+    -2.043083354978218e-09,
+    -1.3967781924397026e-09,
+    1.595770524453874e-11,
+    -1.1719847210980158e-13,
+    6.094795835746165e-16,
+    -1.6424037225906306e-18,
+    0.05309106583845361,
+    -0.006158689841707768,
+    2.9057796072960546e-05,
+    -1.2597414111224824e-08,
+    -1.2440642629636881e-09,
+    1.4601768542433296e-11,
+    -1.0885503796034846e-13,
+    5.820490101322506e-16,
+    -1.776497790671277e-18,
+    0.04088979711535274,
+    -0.006042648488941428,
+    2.895349645019216e-05,
+    -2.1982951538014956e-08,
+    -1.1044169140753647e-09,
+    1.334359471500985e-11,
+    -1.0090860179173395e-13,
+    5.529242115783272e-16,
+    -1.8561754438341726e-18,
+];
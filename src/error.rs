@@ -4,10 +4,14 @@
 //! - `erf`: Calculates the error function.
 //! - `erfc`: Calculates the complementary error function.
 //! - `erfcx`: Calculates the scaled complementary error function.
+//! - `erfi`: Calculates the imaginary error function.
+//! - `ln_erfc`: Calculates the natural logarithm of the complementary error function.
+//! - `ln_erf`: Calculates the natural logarithm of the absolute value of the error function.
 //! - `inverf`: Calculates the inverse of the error function.
 //! - `inverfc`: Calculates the inverse of the complementary error function.
 
-use crate::{utils::frexp, INV_SQRT_PI};
+use crate::{dawson, utils::frexp, EPS, INV_SQRT_PI};
+use std::f64::consts::FRAC_2_SQRT_PI;
 
 /// Calculates the error function.
 ///
@@ -51,6 +55,102 @@ pub fn erfc(x: f64) -> f64 {
     }
 }
 
+/// Calculates the imaginary error function.
+///
+/// The imaginary error function is defined as:
+///
+/// $$ \text{erfi}(x) = -i\,\text{erf}(ix) = \frac{2}{\sqrt{\pi}} \int_0^x e^{t^2} dt = \frac{2}{\sqrt{\pi}} e^{x^2} D(x) $$
+///
+/// where $D(x)$ is Dawson's integral.
+///
+/// # Arguments
+///
+/// * `x` - The input value
+///
+/// # Returns
+///
+/// The value of the imaginary error function at `x`, or `±inf` once it exceeds `f64::MAX`
+pub fn erfi(x: f64) -> f64 {
+    let x2 = x * x;
+    if x2 > 1500f64 {
+        // Far beyond the overflow threshold near |x| = 26.71
+        return f64::INFINITY.copysign(x);
+    }
+    // e^{x^2} overflows slightly before erfi does, so apply it in two halves,
+    // and carry the rounding error of x^2 as a first-order correction
+    let x2_err = x.mul_add(x, -x2);
+    let h = (0.5 * x2).exp();
+    2f64 * INV_SQRT_PI * dawson(x) * (1f64 + x2_err) * h * h
+}
+
+/// Calculates the natural logarithm of the complementary error function.
+///
+/// $$ \ln \text{erfc}(x) = \ln \text{erfcx}(x) - x^2 $$
+///
+/// This stays finite where $\text{erfc}(x)$ underflows, for $x$ beyond about 26.5.
+///
+/// # Arguments
+///
+/// * `x` - The input value
+///
+/// # Returns
+///
+/// The value of $\ln \text{erfc}(x)$
+pub fn ln_erfc(x: f64) -> f64 {
+    if x.is_nan() {
+        x
+    } else if x >= 0.5 {
+        erfcx(x).ln() - x * x
+    } else if x > -0.5 {
+        (-erf_series(x)).ln_1p()
+    } else {
+        erfc(x).ln()
+    }
+}
+
+/// Calculates the natural logarithm of the absolute value of the error function.
+///
+/// $$ \ln |\text{erf}(x)| = \ln(1 - \text{erfc}(|x|)) $$
+///
+/// This keeps the small $\text{erfc}(|x|)$ that $\text{erf}(x)$ rounds away for large $|x|$,
+/// and full relative accuracy near zero.
+/// For $\ln(1 + \text{erf}(x))$ with negative $x$, use `ln_erfc(-x)`.
+///
+/// # Arguments
+///
+/// * `x` - The input value
+///
+/// # Returns
+///
+/// The value of $\ln |\text{erf}(x)|$
+pub fn ln_erf(x: f64) -> f64 {
+    let ax = x.abs();
+    if ax < 0.5 {
+        erf_series(ax).ln()
+    } else {
+        // erfc(|x|) from erfcx, carrying the rounding error of x^2 in the exponent
+        let x2 = ax * ax;
+        let x2_err = ax.mul_add(ax, -x2);
+        (-erfcx(ax) * (-x2).exp() * (1f64 - x2_err)).ln_1p()
+    }
+}
+
+/// Maclaurin series of erf(x) for |x| < 0.5, accurate to full relative precision
+///
+/// $$ \text{erf}(x) = \frac{2}{\sqrt{\pi}} \sum_{n=0}^\infty \frac{(-1)^n x^{2n+1}}{n! (2n+1)} $$
+fn erf_series(x: f64) -> f64 {
+    let x2 = x * x;
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 0f64;
+    while term.abs() > EPS * sum.abs() {
+        n += 1f64;
+        term *= -x2 / n;
+        sum += term / (2f64 * n + 1f64);
+    }
+    FRAC_2_SQRT_PI * sum
+}
+
 /// Calculates the inverse of the complementary error function.
 ///
 /// This function finds x such that:
@@ -1,4 +1,10 @@
-use crate::{dawson, error::erfcx, INV_SQRT_PI};
+use crate::{
+    dawson::im_w_of_x,
+    error::{erf, erfcx},
+    INV_SQRT_PI, SQRT_PI_2,
+};
+use num_complex::Complex64;
+use std::f64::consts::FRAC_2_SQRT_PI;
 
 /// The complex Faddeeva function, or w(z). This is Abramowitz
 /// & Stegun function (7.1.3), also known as the plasma dispersion
@@ -13,7 +19,8 @@ use crate::{dawson, error::erfcx, INV_SQRT_PI};
 ///
 /// ## Outputs:
 /// A tuple of ([`f64`], [`f64`]) where the first
-/// is ℜ(w(z)) and the second is ℑ(w(z)).
+/// is ℜ(w(z)) and the second is ℑ(w(z)). See [`faddeeva_w`]
+/// for the same function on [`Complex64`].
 ///
 /// ### Notes on Implementation:
 /// This is effectively a Rust port of `libcerf` and the
@@ -271,12 +278,282 @@ pub fn faddeeva(re: f64, im: f64) -> (f64, f64) {
     return (res.re, res.im);
 }
 
-/// Computes the scaled Dawson integral of x,
-/// which is 2 * Dawson(x) / sqrt(pi).
-/// This equals the imaginary part of the Faddeeva
-/// function of x, for real x.
-fn im_w_of_x(x: f64) -> f64 {
-    2. * dawson(x) * INV_SQRT_PI
+/// The complex Faddeeva function w(z) for a [`Complex64`] argument.
+///
+/// ### Definition:
+/// w(z) = exp(-z^2) erfc(-iz)
+///
+/// ### Notes on Implementation:
+/// This is the same computation as [`faddeeva`], which takes and returns
+/// the real and imaginary parts as tuples.
+pub fn faddeeva_w(z: Complex64) -> Complex64 {
+    let (re, im) = faddeeva(z.re, z.im);
+    Complex64::new(re, im)
+}
+
+/// The complex error function erf(z).
+///
+/// ### Definition:
+/// erf(z) = 2/sqrt(pi) ∫_0^z exp(-t^2) dt
+///
+/// ### Notes on Implementation:
+/// This is a port of `cerf` from `libcerf`. Away from the origin it uses
+/// erf(z) = 1 - exp(-z^2) w(iz), with the mirror symmetries of w for ℜ(z) < 0,
+/// and switches to Taylor series for small |z| or small |ℜ(z) ℑ(z)|,
+/// where that formula would cancel.
+pub fn cerf(z: Complex64) -> Complex64 {
+    let x = z.re;
+    let y = z.im;
+
+    if y == 0. && x.abs() >= 8e-2 {
+        if x.is_nan() {
+            return Complex64::new(x, y);
+        }
+        return Complex64::new(erf(x), y); // preserve sign of 0
+    }
+    if x == 0. {
+        // Handle y -> Inf manually, since exp(y^2) -> Inf but Im[w(y)] -> 0
+        let im = if y * y > 720. {
+            if y > 0. {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            }
+        } else {
+            f64::exp(y * y) * im_w_of_x(y)
+        };
+        return Complex64::new(x, im); // preserve sign of 0
+    }
+
+    let m_re_z2 = (y - x) * (x + y); // Re(-z^2), being careful of overflow
+    let m_im_z2 = -2. * x * y; // Im(-z^2)
+    if m_re_z2 < -750. {
+        // underflow
+        return Complex64::new(if x >= 0. { 1. } else { -1. }, 0.);
+    }
+
+    if x.abs() < 8e-2 {
+        if y.abs() < 1e-2 {
+            // Taylor series for small |z|:
+            //   erf(z) = 2/sqrt(pi) * z * (1 - z^2/3 + z^4/10 - z^6/42 + z^8/216 + ...)
+            let mz2 = Complex64::new(m_re_z2, m_im_z2);
+            return z
+                * (FRAC_2_SQRT_PI
+                    + mz2
+                        * (0.37612638903183754
+                            + mz2
+                                * (0.11283791670955126
+                                    + mz2 * (0.026866170645131252 + mz2 * 0.005223977625442188))));
+        } else if m_im_z2.abs() < 5e-3 && x.abs() < 5e-3 {
+            // Taylor series in x for small |x| and small |xy|:
+            //   erf(x+iy) = erf(iy) + 2*exp(y^2)/sqrt(pi) *
+            //     [ x * (1 - x^2 * (1+2y^2)/3 + x^4 * (3+12y^2+4y^4)/30 + ...
+            //       - i * x^2 * y * (1 - x^2 * (3+2y^2)/6 + ...) ]
+            let x2 = x * x;
+            let y2 = y * y;
+            let expy2 = f64::exp(y2);
+            return Complex64::new(
+                expy2
+                    * x
+                    * (FRAC_2_SQRT_PI - x2 * (0.37612638903183754 + 0.7522527780636751 * y2)
+                        + x2 * x2
+                            * (0.11283791670955126
+                                + y2 * (0.45135166683820505 + 0.15045055561273502 * y2))),
+                expy2
+                    * (im_w_of_x(y)
+                        - x2 * y
+                            * (FRAC_2_SQRT_PI
+                                - x2 * (0.5641895835477563 + 0.37612638903183754 * y2))),
+            );
+        }
+    }
+
+    // Avoid the complex exp, which gives spurious NaN values when
+    // multiplying w in an overflow situation
+    let phase = Complex64::new(f64::cos(m_im_z2), f64::sin(m_im_z2));
+    if x >= 0. {
+        1. - f64::exp(m_re_z2) * (phase * faddeeva_w(Complex64::new(-y, x)))
+    } else {
+        f64::exp(m_re_z2) * (phase * faddeeva_w(Complex64::new(y, -x))) - 1.
+    }
+}
+
+/// The complex complementary error function erfc(z).
+///
+/// ### Definition:
+/// erfc(z) = 1 - erf(z) = exp(-z^2) w(iz)
+///
+/// ### Notes on Implementation:
+/// This is a port of `cerfc` from `libcerf`, using 2 - erfc(-z) for ℜ(z) < 0.
+pub fn cerfc(z: Complex64) -> Complex64 {
+    let x = z.re;
+    let y = z.im;
+
+    if x == 0. {
+        // Handle y -> Inf manually, since exp(y^2) -> Inf but Im[w(y)] -> 0
+        let im = if y * y > 720. {
+            if y > 0. {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            }
+        } else {
+            -f64::exp(y * y) * im_w_of_x(y)
+        };
+        return Complex64::new(1., im);
+    }
+    if y == 0. {
+        if x * x > 750. {
+            // underflow
+            return Complex64::new(if x >= 0. { 0. } else { 2. }, -y); // preserve sign of 0
+        }
+        let re = if x >= 0. {
+            f64::exp(-x * x) * erfcx(x)
+        } else {
+            2. - f64::exp(-x * x) * erfcx(-x)
+        };
+        return Complex64::new(re, -y); // preserve sign of 0
+    }
+
+    let m_re_z2 = (y - x) * (x + y); // Re(-z^2), being careful of overflow
+    let m_im_z2 = -2. * x * y; // Im(-z^2)
+    if m_re_z2 < -750. {
+        // underflow
+        return Complex64::new(if x >= 0. { 0. } else { 2. }, 0.);
+    }
+
+    let mz2 = Complex64::new(m_re_z2, m_im_z2);
+    if x >= 0. {
+        mz2.exp() * faddeeva_w(Complex64::new(-y, x))
+    } else {
+        2. - mz2.exp() * faddeeva_w(Complex64::new(y, -x))
+    }
+}
+
+/// The complex imaginary error function erfi(z).
+///
+/// ### Definition:
+/// erfi(z) = -i erf(iz)
+pub fn cerfi(z: Complex64) -> Complex64 {
+    let e = cerf(Complex64::new(-z.im, z.re));
+    Complex64::new(e.im, -e.re)
+}
+
+/// The complex scaled complementary error function erfcx(z).
+///
+/// ### Definition:
+/// erfcx(z) = exp(z^2) erfc(z) = w(iz)
+pub fn cerfcx(z: Complex64) -> Complex64 {
+    faddeeva_w(Complex64::new(-z.im, z.re))
+}
+
+/// The complex Dawson function F(z).
+///
+/// ### Definition:
+/// F(z) = sqrt(pi)/2 exp(-z^2) erfi(z) = i sqrt(pi)/2 (exp(-z^2) - w(z))
+///
+/// ### Notes on Implementation:
+/// This is a port of `cdawson` from `libcerf`. Near the real axis, where the
+/// difference of exp(-z^2) and w(z) cancels, it uses a Taylor series in ℑ(z)
+/// around the real Dawson function, or a continued fraction for large |ℜ(z)|.
+pub fn cdawson(z: Complex64) -> Complex64 {
+    let x = z.re;
+    let y = z.im;
+
+    // Handle the axes separately for speed and proper handling of x or y = Inf or NaN
+    if y == 0. {
+        return Complex64::new(SQRT_PI_2 * im_w_of_x(x), -y); // preserve sign of 0
+    }
+    if x == 0. {
+        let y2 = y * y;
+        if y2 < 2.5e-5 {
+            // Taylor expansion
+            return Complex64::new(
+                x, // preserve sign of 0
+                y * (1. + y2 * (0.6666666666666666 + y2 * 0.26666666666666666)),
+            );
+        }
+        let im = SQRT_PI_2
+            * if y >= 0. {
+                f64::exp(y2) - erfcx(y)
+            } else {
+                erfcx(-y) - f64::exp(y2)
+            };
+        return Complex64::new(x, im); // preserve sign of 0
+    }
+
+    let m_re_z2 = (y - x) * (x + y); // Re(-z^2), being careful of overflow
+    let m_im_z2 = -2. * x * y; // Im(-z^2)
+    let mz2 = Complex64::new(m_re_z2, m_im_z2); // -z^2
+
+    if y.abs() < 5e-3 {
+        if x.abs() < 5e-3 {
+            // Taylor series for small |z|:
+            //   dawson(z) = z - 2/3 z^3 + 4/15 z^5 + ...
+            return z * (1. + mz2 * (0.6666666666666666 + mz2 * 0.26666666666666666));
+        } else if m_im_z2.abs() < 5e-3 {
+            return cdawson_real_axis(x, y);
+        }
+    } else if y.is_nan() {
+        return Complex64::new(if x == 0. { 0. } else { f64::NAN }, f64::NAN);
+    }
+
+    let res = if y >= 0. {
+        mz2.exp() - faddeeva_w(z)
+    } else {
+        faddeeva_w(-z) - mz2.exp()
+    };
+    SQRT_PI_2 * Complex64::new(-res.im, res.re)
+}
+
+/// Taylor series of the Dawson function in y for small |y| and small |xy|.
+///
+/// dawson(x + iy)
+///   = D + y^2 (D + x - 2Dx^2)
+///   + y^4 (D/2 + 5x/6 - 2Dx^2 - x^3/3 + 2Dx^4/3)
+///   + iy [ (1-2Dx) + 2/3 y^2 (1 - 3Dx - x^2 + 2Dx^3)
+///   + y^4/15 (4 - 15Dx - 9x^2 + 20Dx^3 + 2x^4 - 4Dx^5) ] + ...
+///
+/// where D = dawson(x). For large |x|, 2Dx -> 1 and the leading terms cancel,
+/// so D is replaced by the continued fraction
+/// dawson(x) = 0.5 / (x-0.5/(x-1/(x-1.5/(x-2/(x-2.5/(x...))))))
+/// truncated after 6 terms, or after 1 and 2 terms for |x| > 5e7.
+fn cdawson_real_axis(x: f64, y: f64) -> Complex64 {
+    let x2 = x * x;
+    if x2 > 1600. {
+        // |x| > 40
+        let y2 = y * y;
+        if x2 > 25e14 {
+            // |x| > 5e7
+            let xy2 = (x * y) * (x * y);
+            return Complex64::new(
+                (0.5 + y2 * (0.5 + 0.25 * y2 - 0.16666666666666666 * xy2)) / x,
+                y * (-1.
+                    + y2 * (-0.6666666666666666 + 0.13333333333333333 * xy2
+                        - 0.26666666666666666 * y2))
+                    / (2. * x2 - 1.),
+            );
+        }
+        return (1. / (-15. + x2 * (90. + x2 * (-60. + 8. * x2))))
+            * Complex64::new(
+                x * (33. + x2 * (-28. + 4. * x2) + y2 * (18. - 4. * x2 + 4. * y2)),
+                y * (-15. + x2 * (24. - 4. * x2) + y2 * (4. * x2 - 10. - 4. * y2)),
+            );
+    }
+    let d = SQRT_PI_2 * im_w_of_x(x);
+    let y2 = y * y;
+    Complex64::new(
+        d + y2 * (d + x - 2. * d * x2)
+            + y2 * y2
+                * (d * (0.5 - x2 * (2. - 0.6666666666666666 * x2))
+                    + x * (0.8333333333333334 - 0.3333333333333333 * x2)),
+        y * (1. - 2. * d * x
+            + y2 * 0.6666666666666666 * (1. - x2 - d * x * (3. - 2. * x2))
+            + y2 * y2
+                * (0.26666666666666666
+                    - x2 * (0.6 - 0.13333333333333333 * x2)
+                    - d * x * (1. - x2 * (1.3333333333333333 - 0.26666666666666666 * x2)))),
+    )
 }
 
 /// As in erfcx_chebyshev_coeffs.rs, these are coefficients for the Taylor expansion of w().
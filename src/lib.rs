@@ -10,18 +10,28 @@ pub use lambert_w::{lambert_w0, lambert_wm1, sp_lambert_w0, sp_lambert_wm1};
 pub mod utils;
 
 pub mod gamma;
-pub use gamma::{gamma, gammp, gammq, invgammp, ln_gamma};
+pub use gamma::{
+    barnes_g, cgamma, cln_gamma, digamma, gamma, gamma_delta_ratio, gamma_lower,
+    gamma_lower_scaled, gamma_ratio, gamma_upper, gamma_upper_scaled, gammp, gammp_derivative_a,
+    gammp_derivative_x, gammp_interval, gammq, gammq_derivative_a, gammq_derivative_x, invgammp,
+    invgammp_a, invgammq, invgammq_a, ln_barnes_g, ln_gamma, ln_gamma_sign, ln_gammp, ln_gammq,
+    multigamma, multigammaln, polygamma, rgamma, trigamma,
+};
 
 pub mod beta;
-pub use beta::{beta, betai, invbetai};
+pub use beta::{
+    beta, beta_lower, beta_upper, betai, betai_derivative_a, betai_derivative_b, betaic, invbetai,
+    invbetai_a, invbetai_b, invbetai_info, ln_beta,
+};
 
 pub mod error;
-pub use error::{erf, erfc, inverf, inverfc};
+pub use error::{erf, erfc, erfcx, erfi, inverf, inverfc, ln_erf, ln_erfc};
 
 pub mod dawson;
 pub use dawson::dawson;
 
 pub mod faddeeva;
+pub use faddeeva::{cdawson, cerf, cerfc, cerfcx, cerfi, faddeeva, faddeeva_w};
 
 pub mod bessel;
 pub use bessel::{
@@ -35,6 +45,7 @@ pub use bessel::{
 const EPS: f64 = f64::EPSILON;
 const FPMIN: f64 = f64::MIN_POSITIVE / EPS;
 const INV_SQRT_PI: f64 = 0.5641895835_4775628694;
+const SQRT_PI_2: f64 = 0.886226925452758;
 // For gamma functions & beta functions
 const Y: [f64; 18] = [
     0.0021695375159141994,
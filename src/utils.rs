@@ -2,7 +2,12 @@
 //!
 //! It includes the following main functions:
 //! - `factorial`: Calculates the factorial of a non-negative integer.
+//! - `ln_factorial`: Calculates the natural logarithm of the factorial.
 //! - `rising_factorial`: Computes the rising factorial (also known as Pochhammer function).
+//! - `pochhammer`: Calculates the Pochhammer symbol for real arguments.
+//! - `falling_factorial`: Calculates the falling factorial for real arguments.
+//! - `binomial`: Calculates the binomial coefficient for real arguments.
+//! - `ln_binomial`: Calculates the natural logarithm of the absolute binomial coefficient.
 //! - `frexp`: Decomposes a floating-point number into its mantissa and exponent.
 //! - `ldexp`: Multiplies a floating-point number by a power of two.
 //! - `sign`: Returns a value with the magnitude of one argument and the sign of another.
@@ -10,11 +15,165 @@
 //! These utility functions are primarily used internally by other functions in the library,
 //! but they may also be useful for users who need to perform similar calculations.
 
+use crate::{beta, gamma_delta_ratio, gamma_ratio, ln_gamma, rgamma};
+
+/// Largest integer argument for which the product formulas are used
+const MAX_PRODUCT: f64 = 100f64;
+
 /// Precise implementation of factorial
 pub fn factorial(n: usize) -> f64 {
     rising_factorial(1f64, n)
 }
 
+/// Natural logarithm of the factorial
+///
+/// $$ \ln(n!) = \ln \Gamma(n+1) $$
+///
+/// Unlike `factorial(n).ln()`, this stays finite for `n` > 170.
+pub fn ln_factorial(n: usize) -> f64 {
+    if n <= 170 {
+        factorial(n).ln()
+    } else {
+        ln_gamma(n as f64 + 1f64)
+    }
+}
+
+/// Pochhammer symbol (rising factorial) for real arguments
+///
+/// $$ (x)_a = \frac{\Gamma(x+a)}{\Gamma(x)} $$
+///
+/// For integer `a` this is the product $x(x+1) \cdots (x+a-1)$, which is evaluated
+/// directly for moderate `a` and is exactly zero when the product contains a zero factor.
+/// Otherwise it is computed with [`gamma_delta_ratio`](crate::gamma::gamma_delta_ratio).
+pub fn pochhammer(x: f64, a: f64) -> f64 {
+    if x.is_nan() || a.is_nan() {
+        return f64::NAN;
+    }
+    if a == a.floor() && a.abs() <= MAX_PRODUCT {
+        return if a >= 0f64 {
+            rising_factorial(x, a as usize)
+        } else {
+            // (x)_{-n} = 1 / ((x-1)(x-2)...(x-n))
+            1f64 / rising_factorial(x + a, (-a) as usize)
+        };
+    }
+    if x <= 0f64 && x == x.floor() && a == a.floor() {
+        // Γ(x) has a pole, but the product stays finite:
+        // (x)_a = (-1)^a Γ(1-x) / Γ(1-x-a) and (x)_{-n} = (-1)^n / (1-x)_n
+        let sign = if a % 2f64 == 0f64 { 1f64 } else { -1f64 };
+        return if a > -x {
+            0f64
+        } else if a >= 0f64 {
+            sign * gamma_ratio(1f64 - x, 1f64 - x - a)
+        } else {
+            sign / gamma_delta_ratio(1f64 - x, -a)
+        };
+    }
+    gamma_delta_ratio(x, a)
+}
+
+/// Falling factorial for real arguments
+///
+/// $$ x^{\underline{a}} = \frac{\Gamma(x+1)}{\Gamma(x-a+1)} $$
+///
+/// For integer `a` this is the product $x(x-1) \cdots (x-a+1)$.
+pub fn falling_factorial(x: f64, a: f64) -> f64 {
+    if x.is_nan() || a.is_nan() {
+        return f64::NAN;
+    }
+    if a == a.floor() && a.abs() <= MAX_PRODUCT {
+        // x(x-1)...(x-n+1) = (x-n+1)_n
+        return if a >= 0f64 {
+            rising_factorial(x - a + 1f64, a as usize)
+        } else {
+            1f64 / rising_factorial(x + 1f64, (-a) as usize)
+        };
+    }
+    gamma_ratio(x + 1f64, x - a + 1f64)
+}
+
+/// Binomial coefficient for real arguments
+///
+/// $$ \binom{n}{k} = \frac{\Gamma(n+1)}{\Gamma(k+1) \Gamma(n-k+1)} $$
+///
+/// Non-negative integer arguments are computed exactly in integer arithmetic as long as
+/// the intermediate values fit, so the result is correctly rounded. Integer `k` with real `n`
+/// uses the product $\prod_{i=1}^k (n-k+i)/i$, and the general case uses the beta function.
+/// Negative integer `n` is first reflected with $\binom{n}{k} = (-1)^k \binom{k-n-1}{k}$.
+pub fn binomial(n: f64, k: f64) -> f64 {
+    if n.is_nan() || k.is_nan() {
+        return f64::NAN;
+    }
+    let k_int = k == k.floor();
+    if k_int && k < 0f64 && (n >= 0f64 || n != n.floor()) {
+        return 0f64;
+    }
+    if n < 0f64 && n == n.floor() && k_int && k >= 0f64 {
+        // C(n, k) = (-1)^k C(k-n-1, k) keeps away from the poles of Γ(n+1)
+        let sign = if k % 2f64 == 0f64 { 1f64 } else { -1f64 };
+        return sign * binomial(k - n - 1f64, k);
+    }
+    if n >= 0f64 && n == n.floor() && k_int {
+        if k > n {
+            return 0f64;
+        }
+        if let Some(r) = binomial_exact(n, k.min(n - k)) {
+            return r;
+        }
+    }
+    if k_int && k >= 0f64 {
+        let kk = if n >= 0f64 && n == n.floor() {
+            k.min(n - k)
+        } else {
+            k
+        };
+        if kk <= MAX_PRODUCT {
+            let m = kk as usize;
+            return (1..=m).fold(1f64, |acc, i| acc * (n - kk + i as f64) / i as f64);
+        }
+    }
+    if n + 1f64 > 0f64 && k + 1f64 > 0f64 && n - k + 1f64 > 0f64 {
+        return 1f64 / ((n + 1f64) * beta(n - k + 1f64, k + 1f64));
+    }
+    gamma_ratio(n + 1f64, k + 1f64) * rgamma(n - k + 1f64)
+}
+
+/// Natural logarithm of the absolute value of the binomial coefficient
+///
+/// $$ \ln \left| \binom{n}{k} \right| $$
+///
+/// This stays finite where [`binomial`] overflows.
+pub fn ln_binomial(n: f64, k: f64) -> f64 {
+    if n < 0f64 && n == n.floor() && k >= 0f64 && k == k.floor() {
+        return ln_binomial(k - n - 1f64, k);
+    }
+    let r = binomial(n, k);
+    if r.is_normal() {
+        r.abs().ln()
+    } else if r == 0f64 {
+        f64::NEG_INFINITY
+    } else if r.is_nan() {
+        f64::NAN
+    } else {
+        ln_gamma(n + 1f64) - ln_gamma(k + 1f64) - ln_gamma(n - k + 1f64)
+    }
+}
+
+/// Exact binomial coefficient C(n, k) for non-negative integers, if the computation fits in u128
+fn binomial_exact(n: f64, k: f64) -> Option<f64> {
+    if n >= u64::MAX as f64 {
+        return None;
+    }
+    let n = n as u128;
+    let k = k as u128;
+    let mut r = 1u128;
+    for i in 1..=k {
+        // r = C(n-k+i-1, i-1), so r (n-k+i) / i is an integer
+        r = r.checked_mul(n - k + i)? / i;
+    }
+    Some(r as f64)
+}
+
 /// Rising factorial via rectangular splitting
 ///
 /// $$ z(z+1) \cdots (z + n - 1) $$
@@ -103,3 +262,83 @@ pub fn sign(a: f64, b: f64) -> f64 {
         -(a.abs())
     }
 }
+
+/// Brent's method for a root of `f` in the bracketing interval [a, b]
+///
+/// `fa` and `fb` are the values of `f` at the end points and must have opposite signs
+/// (or one of them must vanish). Iterates until the interval shrinks below `tol`
+/// relative to the root, falling back to bisection whenever interpolation is slow.
+pub(crate) fn brent<F: Fn(f64) -> f64>(
+    f: F,
+    mut a: f64,
+    mut b: f64,
+    mut fa: f64,
+    mut fb: f64,
+    tol: f64,
+) -> f64 {
+    if fa == 0f64 {
+        return a;
+    } else if fb == 0f64 {
+        return b;
+    }
+    let mut c = b;
+    let mut fc = fb;
+    let mut d = 0f64;
+    let mut e = 0f64;
+    for _ in 0..200 {
+        if (fb > 0f64 && fc > 0f64) || (fb < 0f64 && fc < 0f64) {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+        let tol1 = 2f64 * f64::EPSILON * b.abs() + 0.5 * tol * b.abs().max(f64::MIN_POSITIVE);
+        let xm = 0.5 * (c - b);
+        if xm.abs() <= tol1 || fb == 0f64 {
+            return b;
+        }
+        if e.abs() >= tol1 && fa.abs() > fb.abs() {
+            // Inverse quadratic interpolation (secant if only two points)
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2f64 * xm * s, 1f64 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2f64 * xm * q * (q - r) - (b - a) * (r - 1f64)),
+                    (q - 1f64) * (r - 1f64) * (s - 1f64),
+                )
+            };
+            if p > 0f64 {
+                q = -q;
+            }
+            p = p.abs();
+            let min1 = 3f64 * xm * q - (tol1 * q).abs();
+            let min2 = (e * q).abs();
+            if 2f64 * p < min1.min(min2) {
+                e = d;
+                d = p / q;
+            } else {
+                d = xm;
+                e = d;
+            }
+        } else {
+            d = xm;
+            e = d;
+        }
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol1 { d } else { sign(tol1, xm) };
+        fb = f(b);
+    }
+    b
+}
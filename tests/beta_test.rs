@@ -1,32 +1,90 @@
 use approx::assert_relative_eq;
-use puruspe::{beta, betai, invbetai};
+use puruspe::{beta, betai, invbetai, ln_beta};
 
 #[test]
 fn test_beta() {
     for &(a, b, expected) in BETA_TABLE.iter() {
         let result = beta(a, b);
         let abs_eps = f64::EPSILON;
-        let rel_eps = 1e-10;
+        let rel_eps = 1e-13;
         assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
     }
 }
 
+#[test]
+fn test_beta_negative() {
+    // Values from mpmath
+    let table = [
+        (-0.5, 0.25, 2.6220575542921196),
+        (-1.5, -2.25, -15.3761330124401),
+        (-3.7, 10.0, 452.48050778362585),
+        (-0.3, 1e6, -273.00579532240283),
+        (-20.5, 18.0, 0.00010665845819824589),
+        (1e-3, -2.5, 998.3170978183022),
+    ];
+    for &(a, b, expected) in table.iter() {
+        assert_relative_eq!(beta(a, b), expected, max_relative = 1e-13);
+        assert_relative_eq!(beta(b, a), expected, max_relative = 1e-13);
+    }
+
+    // Poles of Γ(a+b) and finite limits at non-positive integers
+    assert_eq!(beta(-2.5, -1.5), 0f64);
+    assert_eq!(beta(-1.5, 1.5), 0f64);
+    assert_relative_eq!(beta(-5f64, 3f64), -1f64 / 30f64, max_relative = 1e-15);
+    assert_relative_eq!(beta(-2f64, 2f64), 0.5, max_relative = 1e-15);
+    assert_eq!(beta(-2f64, 0.5), f64::INFINITY);
+}
+
+#[test]
+fn test_ln_beta() {
+    // Values from mpmath
+    let table = [
+        (0.5, 0.5, 1.1447298858494002),
+        (2.0, 3.0, -2.4849066497880004),
+        (10.0, 1e10, -217.4566818238231),
+        (1e-5, 1e5, 11.512804563691175),
+        (1e8, 1e8, -138629444.05681732),
+        (3.5, 1e300, -2416.5133740414008),
+        (20.0, 30.0, -33.968820791977386),
+        (1e-300, 2.0, 690.7755278982137),
+        (-0.5, 2.5, 1.5501949939575645),
+        (-0.3, 1e6, 5.6094930232468325),
+        (-2.5, -1.25, 2.627455987185666),
+    ];
+    for &(a, b, expected) in table.iter() {
+        assert_relative_eq!(ln_beta(a, b), expected, max_relative = 1e-14);
+        assert_relative_eq!(ln_beta(b, a), expected, max_relative = 1e-14);
+    }
+    assert_eq!(ln_beta(-2.5, -1.5), f64::NEG_INFINITY);
+}
+
 #[test]
 fn test_betai() {
     for &(a, b, x, expected) in BETAI_TABLE.iter() {
         let result = betai(a, b, x);
         let abs_eps = f64::EPSILON;
-        let rel_eps = 1e-10;
+        let rel_eps = 1e-13;
         assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
     }
 }
 
+#[test]
+fn test_betai_branch_switch() {
+    // The continued fraction converges fastest below x = (a+1)/(a+b+2); with large a and
+    // small b the other branch loses accuracy close to x = 1
+    assert_relative_eq!(
+        betai(500f64, 0.5, 0.9999),
+        0.75188362957096856,
+        max_relative = 1e-13
+    );
+}
+
 #[test]
 fn test_invbetai() {
     for &(a, b, p, expected) in INVBETAI_TABLE.iter() {
         let result = invbetai(p, a, b);
         let abs_eps = f64::EPSILON;
-        let rel_eps = 1e-9;
+        let rel_eps = 1e-13;
         assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
     }
 }
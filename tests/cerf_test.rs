@@ -0,0 +1,681 @@
+use num_complex::Complex64;
+use puruspe::{cdawson, cerf, cerfc, cerfcx, cerfi};
+
+fn check(name: &str, f: fn(Complex64) -> Complex64, table: &[(f64, f64, f64, f64)], rel_eps: f64) {
+    for &(x, y, re, im) in table.iter() {
+        let result = f(Complex64::new(x, y));
+        let expected = Complex64::new(re, im);
+        assert!(
+            (result - expected).norm() <= rel_eps * expected.norm(),
+            "{}({}+{}i) = {}, expected {}",
+            name,
+            x,
+            y,
+            result,
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_cerf() {
+    check("cerf", cerf, &CERF_TABLE, 1e-13);
+    check("cerf", cerf, &CERF_AXIS_TABLE, 1e-13);
+}
+
+#[test]
+fn test_cerfc() {
+    check("cerfc", cerfc, &CERFC_TABLE, 1e-13);
+    check("cerfc", cerfc, &CERFC_AXIS_TABLE, 1e-13);
+}
+
+#[test]
+fn test_cerfi() {
+    check("cerfi", cerfi, &CERFI_TABLE, 1e-13);
+    check("cerfi", cerfi, &CERFI_AXIS_TABLE, 1e-13);
+}
+
+#[test]
+fn test_cerfcx() {
+    check("cerfcx", cerfcx, &CERFCX_TABLE, 1e-13);
+    check("cerfcx", cerfcx, &CERFCX_AXIS_TABLE, 1e-13);
+}
+
+#[test]
+fn test_cdawson() {
+    check("cdawson", cdawson, &CDAWSON_TABLE, 1e-13);
+    check("cdawson", cdawson, &CDAWSON_AXIS_TABLE, 1e-13);
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Tables from scripts/cerf_table.py
+// └─────────────────────────────────────────────────────────┘
+const CERF_TABLE: [(f64, f64, f64, f64); 12] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        6.4261291485482053e-01,
+        4.5788139443519221e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        -2.0000000000000000e+00,
+        -5.3664356577856498e-01,
+        5.0491437034470348e+00,
+    ),
+    (
+        -1.5000000000000000e+00,
+        7.5000000000000000e-01,
+        -1.0486730028174112e+00,
+        2.7906540765740642e-02,
+    ),
+    (
+        -2.9999999999999999e-01,
+        -2.5000000000000000e+00,
+        -1.1449450947459658e+02,
+        -2.6191395067040958e+01,
+    ),
+    (
+        3.0000000000000000e+00,
+        4.0000000000000000e+00,
+        -1.2018699139507945e+02,
+        -2.7750337293623904e+01,
+    ),
+    (
+        -6.0000000000000000e+00,
+        2.0000000000000000e+00,
+        -9.9999999999999922e-01,
+        -8.1644486994338535e-16,
+    ),
+    (
+        1.0000000000000000e+01,
+        -1.0000000000000000e+01,
+        9.6164937427247488e-01,
+        1.0987684608193988e-02,
+    ),
+    (
+        -2.5000000000000000e+01,
+        1.5000000000000000e+00,
+        -1.0000000000000000e+00,
+        2.2095785476216680e-56,
+    ),
+    (
+        1.0000000000000000e-03,
+        1.0000000000000000e-03,
+        1.1283799193478393e-03,
+        1.1283784148422831e-03,
+    ),
+    (
+        -2.0000000000000000e-02,
+        5.0000000000000001e-03,
+        -2.2565138662845517e-02,
+        5.6396864881852540e-03,
+    ),
+    (
+        2.5000000000000000e-01,
+        -5.0000000000000003e-02,
+        2.7698969326316902e-01,
+        -5.3039377846516113e-02,
+    ),
+    (
+        -1.0000000000000001e-01,
+        2.9999999999999999e-01,
+        -1.2298040809618913e-01,
+        3.4526497009870288e-01,
+    ),
+];
+
+const CERF_AXIS_TABLE: [(f64, f64, f64, f64); 8] = [
+    (
+        5.0000000000000000e-01,
+        0.0000000000000000e+00,
+        5.2049987781304652e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        -2.0000000000000000e+00,
+        0.0000000000000000e+00,
+        -9.9532226501895271e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        6.0000000000000000e+00,
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        0.0000000000000000e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        5.0000000000000000e-01,
+        0.0000000000000000e+00,
+        6.1495209469651102e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        -2.0000000000000000e+00,
+        0.0000000000000000e+00,
+        -1.8564802414575553e+01,
+    ),
+    (
+        0.0000000000000000e+00,
+        6.0000000000000000e+00,
+        0.0000000000000000e+00,
+        4.1127514558282388e+14,
+    ),
+    (
+        4.0000000000000001e-03,
+        -5.0000000000000000e-01,
+        5.7954237573944528e-03,
+        -6.1494050386445076e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        1.0000000000000000e-04,
+        9.6610514825926508e-01,
+        1.1893028783610933e-05,
+    ),
+];
+
+const CERFC_TABLE: [(f64, f64, f64, f64); 12] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        3.5738708514517947e-01,
+        -4.5788139443519221e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        -2.0000000000000000e+00,
+        1.5366435657785651e+00,
+        -5.0491437034470348e+00,
+    ),
+    (
+        -1.5000000000000000e+00,
+        7.5000000000000000e-01,
+        2.0486730028174112e+00,
+        -2.7906540765740642e-02,
+    ),
+    (
+        -2.9999999999999999e-01,
+        -2.5000000000000000e+00,
+        1.1549450947459658e+02,
+        2.6191395067040958e+01,
+    ),
+    (
+        3.0000000000000000e+00,
+        4.0000000000000000e+00,
+        1.2118699139507945e+02,
+        2.7750337293623904e+01,
+    ),
+    (
+        -6.0000000000000000e+00,
+        2.0000000000000000e+00,
+        1.9999999999999993e+00,
+        8.1644486994338535e-16,
+    ),
+    (
+        1.0000000000000000e+01,
+        -1.0000000000000000e+01,
+        3.8350625727525144e-02,
+        -1.0987684608193988e-02,
+    ),
+    (
+        -2.5000000000000000e+01,
+        1.5000000000000000e+00,
+        2.0000000000000000e+00,
+        7.2018730586695125e-59,
+    ),
+    (
+        1.0000000000000000e-03,
+        1.0000000000000000e-03,
+        9.9887162008065211e-01,
+        -1.1283784148422831e-03,
+    ),
+    (
+        -2.0000000000000000e-02,
+        5.0000000000000001e-03,
+        1.0225651386628456e+00,
+        -5.6396864881852540e-03,
+    ),
+    (
+        2.5000000000000000e-01,
+        -5.0000000000000003e-02,
+        7.2301030673683098e-01,
+        5.3039377846516113e-02,
+    ),
+    (
+        -1.0000000000000001e-01,
+        2.9999999999999999e-01,
+        1.1229804080961892e+00,
+        -3.4526497009870288e-01,
+    ),
+];
+
+const CERFC_AXIS_TABLE: [(f64, f64, f64, f64); 8] = [
+    (
+        5.0000000000000000e-01,
+        0.0000000000000000e+00,
+        4.7950012218695348e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        -2.0000000000000000e+00,
+        0.0000000000000000e+00,
+        1.9953222650189528e+00,
+        0.0000000000000000e+00,
+    ),
+    (
+        6.0000000000000000e+00,
+        0.0000000000000000e+00,
+        2.1519736712498913e-17,
+        0.0000000000000000e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        5.0000000000000000e-01,
+        1.0000000000000000e+00,
+        -6.1495209469651102e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        -2.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.8564802414575553e+01,
+    ),
+    (
+        0.0000000000000000e+00,
+        6.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -4.1127514558282388e+14,
+    ),
+    (
+        4.0000000000000001e-03,
+        -5.0000000000000000e-01,
+        9.9420457624260550e-01,
+        6.1494050386445076e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        1.0000000000000000e-04,
+        3.3894851740734942e-02,
+        -1.1893028783610933e-05,
+    ),
+];
+
+const CERFI_TABLE: [(f64, f64, f64, f64); 12] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        4.5788139443519221e-01,
+        6.4261291485482053e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        -2.0000000000000000e+00,
+        -1.1259006028815025e-02,
+        -1.0036063427256519e+00,
+    ),
+    (
+        -1.5000000000000000e+00,
+        7.5000000000000000e-01,
+        7.0248101512946748e-02,
+        2.8902252730794933e+00,
+    ),
+    (
+        -2.9999999999999999e-01,
+        -2.5000000000000000e+00,
+        -4.4277444763268244e-04,
+        -1.0000153774253389e+00,
+    ),
+    (
+        3.0000000000000000e+00,
+        4.0000000000000000e+00,
+        -4.9720260544966039e-05,
+        9.9991066178539167e-01,
+    ),
+    (
+        -6.0000000000000000e+00,
+        2.0000000000000000e+00,
+        -7.6985024539741724e+11,
+        -7.0737132541612666e+12,
+    ),
+    (
+        1.0000000000000000e+01,
+        -1.0000000000000000e+01,
+        -1.0987684608193988e-02,
+        -9.6164937427247488e-01,
+    ),
+    (
+        -2.5000000000000000e+01,
+        1.5000000000000000e+00,
+        -5.7895847586588064e+268,
+        -2.8558169895479382e+268,
+    ),
+    (
+        1.0000000000000000e-03,
+        1.0000000000000000e-03,
+        1.1283784148422831e-03,
+        1.1283799193478393e-03,
+    ),
+    (
+        -2.0000000000000000e-02,
+        5.0000000000000001e-03,
+        -2.2570028305890721e-02,
+        5.6441059733405323e-03,
+    ),
+    (
+        2.5000000000000000e-01,
+        -5.0000000000000003e-02,
+        2.8733387559182433e-01,
+        -6.0001411566810638e-02,
+    ),
+    (
+        -1.0000000000000001e-01,
+        2.9999999999999999e-01,
+        -1.0340864129817522e-01,
+        3.3173512856039805e-01,
+    ),
+];
+
+const CERFI_AXIS_TABLE: [(f64, f64, f64, f64); 8] = [
+    (
+        5.0000000000000000e-01,
+        0.0000000000000000e+00,
+        6.1495209469651102e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        -2.0000000000000000e+00,
+        0.0000000000000000e+00,
+        -1.8564802414575553e+01,
+        0.0000000000000000e+00,
+    ),
+    (
+        6.0000000000000000e+00,
+        0.0000000000000000e+00,
+        4.1127514558282388e+14,
+        0.0000000000000000e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        5.0000000000000000e-01,
+        0.0000000000000000e+00,
+        5.2049987781304652e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        -2.0000000000000000e+00,
+        0.0000000000000000e+00,
+        -9.9532226501895271e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        6.0000000000000000e+00,
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+    ),
+    (
+        4.0000000000000001e-03,
+        -5.0000000000000000e-01,
+        3.5151396894301202e-03,
+        -5.2050690812054667e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        1.0000000000000000e-04,
+        4.5847330966979767e+00,
+        1.0705763264380152e-03,
+    ),
+];
+
+const CERFCX_TABLE: [(f64, f64, f64, f64); 12] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        5.3315670791217495e-01,
+        -2.3048823138445840e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        -2.0000000000000000e+00,
+        1.4023958136627795e-01,
+        2.2221344017989911e-01,
+    ),
+    (
+        -1.5000000000000000e+00,
+        7.5000000000000000e-01,
+        -7.0744176117033204e+00,
+        -8.5224103648239016e+00,
+    ),
+    (
+        -2.9999999999999999e-01,
+        -2.5000000000000000e+00,
+        -3.7927676489373563e-02,
+        2.4725593262896037e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        4.0000000000000000e+00,
+        6.9790961649648306e-02,
+        -8.9340000240364911e-02,
+    ),
+    (
+        -6.0000000000000000e+00,
+        2.0000000000000000e+00,
+        6.6988860133360547e+13,
+        1.4301429628285247e+14,
+    ),
+    (
+        1.0000000000000000e+01,
+        -1.0000000000000000e+01,
+        2.8279467454232456e-02,
+        2.8138433276336895e-02,
+    ),
+    (
+        -2.5000000000000000e+01,
+        1.5000000000000000e+00,
+        5.2787652057151085e+270,
+        2.2207815401500609e+270,
+    ),
+    (
+        1.0000000000000000e-03,
+        1.0000000000000000e-03,
+        9.9887162233541127e-01,
+        -1.1263806715998664e-03,
+    ),
+    (
+        -2.0000000000000000e-02,
+        5.0000000000000001e-03,
+        1.0229475236786243e+00,
+        -5.8463913875079739e-03,
+    ),
+    (
+        2.5000000000000000e-01,
+        -5.0000000000000003e-02,
+        7.6888669968776024e-01,
+        3.7110581010372616e-02,
+    ),
+    (
+        -1.0000000000000001e-01,
+        2.9999999999999999e-01,
+        1.0156644638577750e+00,
+        -3.8030739619631665e-01,
+    ),
+];
+
+const CERFCX_AXIS_TABLE: [(f64, f64, f64, f64); 8] = [
+    (
+        5.0000000000000000e-01,
+        0.0000000000000000e+00,
+        6.1569034419292590e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        -2.0000000000000000e+00,
+        0.0000000000000000e+00,
+        1.0894090438997797e+02,
+        0.0000000000000000e+00,
+    ),
+    (
+        6.0000000000000000e+00,
+        0.0000000000000000e+00,
+        9.2776567800538348e-02,
+        0.0000000000000000e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        5.0000000000000000e-01,
+        7.7880078307140488e-01,
+        -4.7892517290104347e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        -2.0000000000000000e+00,
+        1.8315638888734179e-02,
+        3.4002621706606623e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        6.0000000000000000e+00,
+        2.3195228302435696e-16,
+        -9.5396208969110760e-02,
+    ),
+    (
+        4.0000000000000001e-03,
+        -5.0000000000000000e-01,
+        7.7620918694376428e-01,
+        4.7582278678059708e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        1.0000000000000000e-04,
+        3.2158541569280713e-01,
+        -1.6362291740325102e-05,
+    ),
+];
+
+const CDAWSON_TABLE: [(f64, f64, f64, f64); 12] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        6.2914469771362780e-01,
+        3.0523946561753884e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        -2.0000000000000000e+00,
+        -1.3388927316482919e+01,
+        1.1828715103889593e+01,
+    ),
+    (
+        -1.5000000000000000e+00,
+        7.5000000000000000e-01,
+        -3.7589341039467972e-01,
+        -2.8867485579415547e-01,
+    ),
+    (
+        -2.9999999999999999e-01,
+        -2.5000000000000000e+00,
+        -4.1853326866400664e+02,
+        -2.9493982519971539e+01,
+    ),
+    (
+        3.0000000000000000e+00,
+        4.0000000000000000e+00,
+        -8.8004253885450453e+02,
+        4.1216449595391867e+02,
+    ),
+    (
+        -6.0000000000000000e+00,
+        2.0000000000000000e+00,
+        -7.5559415742327232e-02,
+        -2.5851367289420311e-02,
+    ),
+    (
+        1.0000000000000000e+01,
+        -1.0000000000000000e+01,
+        -7.4900254150661616e-01,
+        -4.0669680984453055e-01,
+    ),
+    (
+        -2.5000000000000000e+01,
+        1.5000000000000000e+00,
+        -1.9943952259968672e-02,
+        -1.1985524915247292e-03,
+    ),
+    (
+        1.0000000000000000e-03,
+        1.0000000000000000e-03,
+        1.0000013333322667e-03,
+        9.9999866666560010e-04,
+    ),
+    (
+        -2.0000000000000000e-02,
+        5.0000000000000001e-03,
+        -1.9995667003350637e-02,
+        4.9960842673806823e-03,
+    ),
+    (
+        2.5000000000000000e-01,
+        -5.0000000000000003e-02,
+        2.4099064904937084e-01,
+        -4.4067835158930480e-02,
+    ),
+    (
+        -1.0000000000000001e-01,
+        2.9999999999999999e-01,
+        -1.1819483878241459e-01,
+        3.1195229449229073e-01,
+    ),
+];
+
+const CDAWSON_AXIS_TABLE: [(f64, f64, f64, f64); 8] = [
+    (
+        5.0000000000000000e-01,
+        0.0000000000000000e+00,
+        4.2443638350202229e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        -2.0000000000000000e+00,
+        0.0000000000000000e+00,
+        -3.0134038892379195e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        6.0000000000000000e+00,
+        0.0000000000000000e+00,
+        8.4542688974543853e-02,
+        0.0000000000000000e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        5.0000000000000000e-01,
+        0.0000000000000000e+00,
+        5.9229653646932656e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        -2.0000000000000000e+00,
+        0.0000000000000000e+00,
+        -4.8160012114291227e+01,
+    ),
+    (
+        0.0000000000000000e+00,
+        6.0000000000000000e+00,
+        0.0000000000000000e+00,
+        3.8207294789148365e+15,
+    ),
+    (
+        4.0000000000000001e-03,
+        -5.0000000000000000e-01,
+        6.3690885885805074e-03,
+        -5.9227432162772831e-01,
+    ),
+    (
+        1.5000000000000000e+00,
+        1.0000000000000000e-04,
+        4.2824907109668114e-01,
+        -2.8474721516579317e-05,
+    ),
+];
@@ -0,0 +1,266 @@
+use approx::assert_relative_eq;
+use num_complex::Complex64;
+use puruspe::{cgamma, cln_gamma, ln_gamma};
+
+#[test]
+fn test_cln_gamma() {
+    for &(x, y, re, im) in CLN_GAMMA_TABLE.iter() {
+        let result = cln_gamma(Complex64::new(x, y));
+        let expected = Complex64::new(re, im);
+        let rel_eps = 1e-13;
+        assert!(
+            (result - expected).norm() <= rel_eps * expected.norm(),
+            "cln_gamma({}+{}i) = {}, expected {}",
+            x,
+            y,
+            result,
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_cln_gamma_branch_cut() {
+    // The imaginary part is continuous when crossing the real axis away from the cut
+    let above = cln_gamma(Complex64::new(2.5, 1e-12));
+    let below = cln_gamma(Complex64::new(2.5, -1e-12));
+    assert_relative_eq!(above.re, below.re, max_relative = 1e-14);
+    assert!((above.im - below.im).abs() < 1e-11);
+
+    // ... and jumps by a multiple of 2π across the negative real axis
+    let above = cln_gamma(Complex64::new(-2.5, 1e-12));
+    let below = cln_gamma(Complex64::new(-2.5, -1e-12));
+    assert_relative_eq!(above.re, below.re, max_relative = 1e-12);
+    assert_relative_eq!(
+        above.im - below.im,
+        -6f64 * std::f64::consts::PI,
+        epsilon = 1e-10
+    );
+
+    // On the positive real axis it agrees with the real ln_gamma
+    for x in [0.3, 1.7, 4.2, 25.0] {
+        let result = cln_gamma(Complex64::new(x, 0f64));
+        assert_relative_eq!(result.re, ln_gamma(x), max_relative = 1e-9);
+        assert_eq!(result.im, 0f64);
+    }
+}
+
+#[test]
+fn test_cgamma() {
+    for &(x, y, re, im) in CGAMMA_TABLE.iter() {
+        let result = cgamma(Complex64::new(x, y));
+        let expected = Complex64::new(re, im);
+        let rel_eps = 1e-13;
+        assert!(
+            (result - expected).norm() <= rel_eps * expected.norm(),
+            "cgamma({}+{}i) = {}, expected {}",
+            x,
+            y,
+            result,
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_cgamma_poles() {
+    for x in [0f64, -1f64, -7f64] {
+        let result = cgamma(Complex64::new(x, 0f64));
+        assert!(result.re.is_nan() && result.im.is_nan());
+    }
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Tables from scripts/cgamma_table.py
+// └─────────────────────────────────────────────────────────┘
+const CLN_GAMMA_TABLE: [(f64, f64, f64, f64); 20] = [
+    (
+        1.0000000000000000e+00,
+        0.0000000000000000e+00,
+        0.0000000000000000e+00,
+        0.0000000000000000e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        0.0000000000000000e+00,
+        0.0000000000000000e+00,
+        0.0000000000000000e+00,
+    ),
+    (
+        5.0000000000000000e-01,
+        0.0000000000000000e+00,
+        5.7236494292470008e-01,
+        0.0000000000000000e+00,
+    ),
+    (
+        1.1000000000000001e+00,
+        1.0000000000000001e-01,
+        -5.7020229038172845e-02,
+        -4.2066544375627422e-02,
+    ),
+    (
+        2.1000000000000001e+00,
+        -5.0000000000000003e-02,
+        4.4679280442969184e-02,
+        -2.4274272177161417e-02,
+    ),
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        1.1238724280962312e-01,
+        -7.5072920212205074e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        4.0000000000000000e+00,
+        -1.7566267846037842e+00,
+        4.7426644380346579e+00,
+    ),
+    (
+        5.5000000000000000e+00,
+        -2.5000000000000000e+00,
+        3.3582038657578597e+00,
+        -4.1240820132046965e+00,
+    ),
+    (
+        -5.0000000000000000e-01,
+        0.0000000000000000e+00,
+        1.2655121234846454e+00,
+        -3.1415926535897931e+00,
+    ),
+    (
+        -2.5000000000000000e+00,
+        0.0000000000000000e+00,
+        -5.6243716497674054e-02,
+        -9.4247779607693793e+00,
+    ),
+    (
+        -5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        4.5896083308959579e-01,
+        -3.1069236923143957e+00,
+    ),
+    (
+        -3.7000000000000002e+00,
+        -1.2000000000000000e+00,
+        -4.5001703890958797e+00,
+        1.1453541394917544e+01,
+    ),
+    (
+        -5.5000000000000000e+00,
+        8.0000000000000000e+00,
+        -2.4608449235106313e+01,
+        -2.8617713994767273e+00,
+    ),
+    (
+        2.0000000000000000e+01,
+        1.0000000000000000e+00,
+        3.9314259987890608e+01,
+        2.9709616680231354e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        3.0000000000000000e+01,
+        -4.7905549961473305e+01,
+        7.1247745405776087e+01,
+    ),
+    (
+        -5.0000000000000000e+01,
+        1.5000000000000000e+01,
+        -1.9156777699714362e+02,
+        -9.9605641782540189e+01,
+    ),
+    (
+        -5.0000000000000000e+01,
+        -1.5000000000000000e+01,
+        -1.9156777699714362e+02,
+        9.9605641782540189e+01,
+    ),
+    (
+        1.0000000000000000e+05,
+        1.0000000000000000e+05,
+        1.0074050783746975e+06,
+        1.1644893291652666e+06,
+    ),
+    (
+        -1.0000000000000000e+03,
+        1.0000000000000000e-03,
+        -5.9052204248536154e+03,
+        -3.1431565416613926e+03,
+    ),
+    (
+        1.0000000000000001e-05,
+        1.0000000000000001e-05,
+        1.1166346102533607e+01,
+        -7.8540393538960473e-01,
+    ),
+];
+
+const CGAMMA_TABLE: [(f64, f64, f64, f64); 11] = [
+    (
+        1.0000000000000000e+00,
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        0.0000000000000000e+00,
+    ),
+    (
+        5.0000000000000000e+00,
+        0.0000000000000000e+00,
+        2.4000000000000000e+01,
+        0.0000000000000000e+00,
+    ),
+    (
+        5.0000000000000000e-01,
+        0.0000000000000000e+00,
+        1.7724538509055161e+00,
+        0.0000000000000000e+00,
+    ),
+    (
+        -1.5000000000000000e+00,
+        0.0000000000000000e+00,
+        2.3632718012073548e+00,
+        0.0000000000000000e+00,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -1.5494982830181067e-01,
+        -4.9801566811835607e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        4.9801566811835607e-01,
+        -1.5494982830181067e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        -4.0000000000000000e+00,
+        5.2255384713692146e-03,
+        1.7254707929430019e-01,
+    ),
+    (
+        -2.5000000000000000e+00,
+        5.0000000000000000e-01,
+        -3.3387520352243233e-01,
+        -2.0645730796360842e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e+01,
+        1.4238519417891830e+03,
+        -3.4960819733079447e+03,
+    ),
+    (
+        -1.0500000000000000e+01,
+        2.5000000000000000e-01,
+        -1.6501522649716667e-07,
+        -1.1279157912231672e-07,
+    ),
+    (
+        1.5000000000000000e+02,
+        3.0000000000000000e+01,
+        1.7795535970275292e+259,
+        -7.0778296852483254e+258,
+    ),
+];
@@ -1,3 +1,4 @@
+use approx::assert_relative_eq;
 use puruspe::dawson;
 
 #[test]
@@ -5,5 +6,55 @@ fn dawson_test() {
     let expected = 0.13818492867352312; // From Wolfram|Alpha
     let res = dawson(0.14);
     dbg!(expected, res);
-    assert!((expected - res).abs() < 1e-7); // Verify accuracy.
+    assert_relative_eq!(res, expected, max_relative = 1e-15); // Verify accuracy.
 }
+
+#[test]
+fn test_dawson() {
+    for &(x, expected) in DAWSON_TABLE.iter() {
+        let result = dawson(x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-15;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    assert_eq!(dawson(0.0), 0.0);
+    assert!(dawson(f64::NAN).is_nan());
+}
+
+#[test]
+fn test_dawson_above_0_2() {
+    // The Rybicki sum once used for |x| >= 0.2 updated its counters before use,
+    // giving dawson(0.5) ≈ -1.0. These points are all past that switch.
+    for &(x, expected) in [
+        (0.2, 0.19475103336802807),
+        (0.5, 0.4244363835020223),
+        (1.0, 0.5380795069127684),
+        (2.5, 0.2230837221674355),
+        (5.0, 0.10213407442427684),
+    ]
+    .iter()
+    {
+        assert_relative_eq!(dawson(x), expected, max_relative = 1e-15);
+        assert_eq!(dawson(-x), -dawson(x));
+    }
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Tables from scripts/dawson_table.py
+// └─────────────────────────────────────────────────────────┘
+const DAWSON_TABLE: [(f64, f64); 14] = [
+    (1.0000000000000000e-10, 1.0000000000000000e-10),
+    (1.0000000000000000e-02, 9.9993333599992379e-03),
+    (5.0000000000000003e-02, 4.9916749940509250e-02),
+    (1.4000000000000001e-01, 1.3818492867352314e-01),
+    (-5.0000000000000000e-01, -4.2443638350202229e-01),
+    (9.2413887299999997e-01, 5.4104422463518165e-01),
+    (1.5000000000000000e+00, 4.2824907108539861e-01),
+    (-3.0000000000000000e+00, -1.7827103061055830e-01),
+    (1.0000000000000000e+01, 5.0253847187598531e-02),
+    (4.4899999999999999e+01, 1.1138621374229479e-02),
+    (4.5100000000000001e+01, 1.1089201788681790e-02),
+    (-1.0000000000000000e+03, -5.0000025000037501e-04),
+    (1.0000000000000000e+08, 5.0000000000000001e-09),
+    (1.0000000000000001e+300, 5.0000000000000001e-301),
+];
@@ -1,5 +1,5 @@
 use approx::assert_abs_diff_eq;
-use puruspe::{erf, erfc, inverf, inverfc};
+use puruspe::{erf, erfc, erfi, inverf, inverfc, ln_erf, ln_erfc};
 
 #[test]
 fn test_erf() {
@@ -19,6 +19,39 @@ fn test_erfc() {
     }
 }
 
+#[test]
+fn test_erfi() {
+    for &(x, expected) in ERFI_TABLE.iter() {
+        let result = erfi(x);
+        let epsilon = f64::EPSILON + 1e-14 * result.abs();
+        assert_abs_diff_eq!(result, expected, epsilon = epsilon);
+    }
+    assert_eq!(erfi(26.8), f64::INFINITY);
+    assert_eq!(erfi(-1e300), f64::NEG_INFINITY);
+    assert!(erfi(f64::NAN).is_nan());
+}
+
+#[test]
+fn test_ln_erfc() {
+    for &(x, expected) in LN_ERFC_TABLE.iter() {
+        let result = ln_erfc(x);
+        let epsilon = 1e-15 * result.abs();
+        assert_abs_diff_eq!(result, expected, epsilon = epsilon);
+    }
+    assert!(ln_erfc(f64::NAN).is_nan());
+}
+
+#[test]
+fn test_ln_erf() {
+    for &(x, expected) in LN_ERF_TABLE.iter() {
+        let result = ln_erf(x);
+        let epsilon = 1e-15 * result.abs();
+        assert_abs_diff_eq!(result, expected, epsilon = epsilon);
+    }
+    assert_eq!(ln_erf(0.0), f64::NEG_INFINITY);
+    assert!(ln_erf(f64::NAN).is_nan());
+}
+
 #[test]
 fn test_inverf() {
     for &(p, expected) in INVERF_TABLE.iter() {
@@ -67,6 +100,47 @@ const ERFC_TABLE: [(f64, f64); 11] = [
     (1.00000000000000e+01, 2.08848758376254e-45),
 ];
 
+const ERFI_TABLE: [(f64, f64); 10] = [
+    (-3.00000000000000e+00, -1.62999462260157e+03),
+    (-5.00000000000000e-01, -6.14952094696511e-01),
+    (0.00000000000000e+00, 0.00000000000000e+00),
+    (5.00000000000000e-01, 6.14952094696511e-01),
+    (1.50000000000000e+00, 4.58473325728443e+00),
+    (3.00000000000000e+00, 1.62999462260157e+03),
+    (1.00000000000000e-10, 1.12837916709551e-10),
+    (1.00000000000000e+01, 1.52430742270867e+42),
+    (-2.00000000000000e+01, -1.47479753962879e+172),
+    (2.66000000000000e+01, 4.13289605305205e+305),
+];
+
+const LN_ERFC_TABLE: [(f64, f64); 13] = [
+    (-1.0000000000000000e+05, 6.9314718055994529e-01),
+    (-5.0000000000000000e+00, 6.9314718055917657e-01),
+    (-5.0000000000000000e-01, 4.1903914777555956e-01),
+    (-1.0000000000000000e-10, 1.1283791670318507e-10),
+    (0.0000000000000000e+00, 0.0000000000000000e+00),
+    (1.0000000000000000e-10, -1.1283791671591746e-10),
+    (2.9999999999999999e-01, -3.9843005144008525e-01),
+    (5.0000000000000000e-01, -7.3501112983708439e-01),
+    (2.0000000000000000e+00, -5.3649412646166379e+00),
+    (1.0000000000000000e+01, -1.0287988902484489e+02),
+    (3.0000000000000000e+01, -9.0397411711064387e+02),
+    (1.0000000000000000e+03, -1.0000074801207219e+06),
+    (1.0000000000000000e+05, -1.0000000012085291e+10),
+];
+
+const LN_ERF_TABLE: [(f64, f64); 9] = [
+    (-1.0000000000000000e+01, -2.0884875837625449e-45),
+    (-3.0000000000000000e+00, -2.2090740997207638e-05),
+    (-2.9999999999999999e-01, -1.1128326419437544e+00),
+    (1.0000000000000000e-300, -6.9065474566057844e+02),
+    (1.0000000000000000e-10, -2.2905068692305210e+01),
+    (5.0000000000000000e-01, -6.5296562567633121e-01),
+    (2.0000000000000000e+00, -4.6887098216288363e-03),
+    (6.0000000000000000e+00, -2.1519736712498913e-17),
+    (2.0000000000000000e+01, -5.3958656116079012e-176),
+];
+
 const INVERF_TABLE: [(f64, f64); 6] = [
     (-9.90000000000000e-01, -1.82138636771845e+00),
     (-5.00000000000000e-01, -4.76936276204470e-01),
@@ -1,5 +1,7 @@
-use approx::assert_ulps_eq;
-use puruspe::utils::factorial;
+use approx::{assert_relative_eq, assert_ulps_eq};
+use puruspe::utils::{
+    binomial, factorial, falling_factorial, ln_binomial, ln_factorial, pochhammer,
+};
 
 #[test]
 fn test_factorial() {
@@ -14,3 +16,115 @@ fn test_factorial() {
         7257415615307998967396728211129263114716991681296451376543577798900561843401706157852350749242617459511490991237838520776666022565442753025328900773207510902400430280058295603966612599658257104398558294257568966313439612262571094946806711205568880457193340212661452800000000000000000000000000000000000000000.0
     );
 }
+
+#[test]
+fn test_ln_factorial() {
+    for i in 0..=170 {
+        assert_relative_eq!(ln_factorial(i), factorial(i).ln(), max_relative = 1e-15);
+    }
+    // Values from mpmath
+    assert_relative_eq!(ln_factorial(1000), 5912.128178488163, max_relative = 1e-15);
+    assert_relative_eq!(
+        ln_factorial(1000000),
+        12815518.384658169,
+        max_relative = 1e-15
+    );
+}
+
+#[test]
+fn test_pochhammer() {
+    assert_eq!(pochhammer(3f64, 4f64), 360f64);
+    assert_eq!(pochhammer(-3f64, 5f64), 0f64);
+    assert_eq!(pochhammer(-3f64, 2f64), 6f64);
+    assert_eq!(pochhammer(5f64, -2f64), 1f64 / 12f64);
+    assert_eq!(pochhammer(-3f64, 200f64), 0f64);
+
+    // Values from mpmath
+    let table = [
+        (0.5, 0.25, 0.6913673390362933),
+        (3.0, 2.5, 26.17138889227676),
+        (1e6, 1e-9, 1.00000001381551),
+        (-2.5, 3.0, -1.875),
+        (10.0, 150.0, 8.120321518119043e+276),
+    ];
+    for &(x, a, expected) in table.iter() {
+        assert_relative_eq!(pochhammer(x, a), expected, max_relative = 1e-13);
+    }
+}
+
+#[test]
+fn test_falling_factorial() {
+    assert_eq!(falling_factorial(10f64, 3f64), 720f64);
+    assert_eq!(falling_factorial(3f64, 5f64), 0f64);
+    assert_eq!(falling_factorial(5f64, -1f64), 1f64 / 6f64);
+    // Value from mpmath
+    assert_relative_eq!(
+        falling_factorial(5.5, 2.5),
+        47.98087963584073,
+        max_relative = 1e-13
+    );
+}
+
+#[test]
+fn test_binomial() {
+    assert_eq!(binomial(52f64, 5f64), 2598960f64);
+    assert_eq!(binomial(10f64, 11f64), 0f64);
+    assert_eq!(binomial(10f64, -1f64), 0f64);
+    assert_eq!(binomial(-3f64, 2f64), 6f64);
+    // Exact integer computation, correctly rounded
+    assert_eq!(binomial(100f64, 50f64), 100891344545564193334812497256f64);
+    for n in 0..60u32 {
+        let mut row = 1u64;
+        for k in 0..=n {
+            assert_eq!(binomial(n as f64, k as f64), row as f64);
+            row = row * (n - k) as u64 / (k + 1) as u64;
+        }
+    }
+
+    // Values from mpmath
+    let table = [
+        (2.5, 1.5, 2.5),
+        (-3.5, 4.0, 23.4609375),
+        (0.5, 7.0, 0.01611328125),
+    ];
+    for &(n, k, expected) in table.iter() {
+        assert_relative_eq!(binomial(n, k), expected, max_relative = 1e-13);
+    }
+    assert_relative_eq!(
+        binomial(1000f64, 500f64),
+        2.7028824094543655e+299,
+        max_relative = 1e-12
+    );
+    // C(n, k) = (-1)^k C(k-n-1, k) for negative integer n, past the product cutoff
+    assert_eq!(binomial(-1f64, 200f64), 1f64);
+    assert_eq!(binomial(-2f64, 201f64), -202f64);
+    assert_eq!(binomial(-3f64, 150f64), 11476f64);
+    assert_eq!(binomial(-5f64, 1000f64), 42084793751f64);
+}
+
+#[test]
+fn test_ln_binomial() {
+    assert_relative_eq!(
+        ln_binomial(52f64, 5f64),
+        2598960f64.ln(),
+        max_relative = 1e-15
+    );
+    assert_eq!(ln_binomial(10f64, 11f64), f64::NEG_INFINITY);
+    // Values from mpmath
+    assert_relative_eq!(
+        ln_binomial(1e6, 5e5),
+        693140.0470130637,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        ln_binomial(-3.5, 4f64),
+        3.155336804063713,
+        max_relative = 1e-13
+    );
+    assert_eq!(ln_binomial(-1f64, 1000f64), 0f64);
+    assert_relative_eq!(
+        ln_binomial(-1000f64, 2000f64),
+        1904.2737117549095,
+        max_relative = 1e-13
+    );
+}
@@ -20,7 +20,8 @@
 //! gcc -o myprogram myprogram.c -lcerf -lm
 //! ```
 
-use puruspe::faddeeva::faddeeva;
+use num_complex::Complex64;
+use puruspe::{faddeeva, faddeeva_w};
 
 const MAX_ERR: f64 = 1e-15;
 
@@ -41,6 +42,19 @@ fn test_faddeeva() {
     }
 }
 
+#[test]
+fn test_faddeeva_w() {
+    let mut z = Complex64::new(0., 0.);
+    for &(re, im) in W_OF_Z_TABLE.iter() {
+        z += Complex64::new(0.01, 0.01);
+        let w = faddeeva_w(z);
+
+        assert!((re - w.re).abs() < MAX_ERR);
+        assert!((im - w.im).abs() < MAX_ERR);
+        assert_eq!((w.re, w.im), faddeeva(z.re, z.im));
+    }
+}
+
 const W_OF_Z_TABLE: [(f64, f64); 1000] = [
     (0.98871769295495482, 0.01108529605747728),
     (0.97744413655320983, 0.02177961561927827),
@@ -0,0 +1,177 @@
+use approx::assert_relative_eq;
+use puruspe::{gamma_delta_ratio, gamma_ratio};
+
+#[test]
+fn test_gamma_ratio() {
+    for &(a, b, expected) in GAMMA_RATIO_TABLE.iter() {
+        let result = gamma_ratio(a, b);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_gamma_ratio_poles() {
+    assert!(gamma_ratio(-2f64, 1.5).is_nan());
+    assert_eq!(gamma_ratio(1.5, -3f64), 0f64);
+}
+
+#[test]
+fn test_gamma_delta_ratio() {
+    for &(x, delta, expected) in GAMMA_DELTA_RATIO_TABLE.iter() {
+        let result = gamma_delta_ratio(x, delta);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Tables from scripts/gamma_ratio_table.py
+// └─────────────────────────────────────────────────────────┘
+const GAMMA_RATIO_TABLE: [(f64, f64, f64); 14] = [
+    (
+        2.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+    ),
+    (
+        5.0000000000000000e-01,
+        1.5000000000000000e+00,
+        2.0000000000000000e+00,
+    ),
+    (
+        3.7000000000000002e+00,
+        1.2000000000000000e+00,
+        4.5423587094633939e+00,
+    ),
+    (
+        1.0000000000000000e+01,
+        2.0000000000000000e+01,
+        2.9831041182950368e-12,
+    ),
+    (
+        2.0000000000000000e+02,
+        1.9050000000000000e+02,
+        5.6186416037772971e+21,
+    ),
+    (
+        1.0005000000000000e+03,
+        1.0000000000000000e+03,
+        3.1618824001815913e+01,
+    ),
+    (
+        1.0000000000000000e+08,
+        1.0000000350000000e+08,
+        9.9999995625000143e-29,
+    ),
+    (
+        2.2000000000000000e+02,
+        1.0000000000000000e+02,
+        1.1123544757155560e+263,
+    ),
+    (
+        4.0000000000000000e+01,
+        1.8000000000000000e+02,
+        1.8276210737735895e-281,
+    ),
+    (
+        1.0000000000000000e-08,
+        1.5000000000000000e+02,
+        2.6254142952347202e-253,
+    ),
+    (
+        -2.5000000000000000e+00,
+        -1.5000000000000000e+00,
+        -4.0000000000000002e-01,
+    ),
+    (
+        -1.0300000000000001e+01,
+        -1.0699999999999999e+01,
+        2.6098001731508882e+00,
+    ),
+    (
+        -5.0000000000000000e-01,
+        3.0000000000000000e+00,
+        -1.7724538509055161e+00,
+    ),
+    (
+        4.5000000000000000e+00,
+        -3.5000000000000000e+00,
+        4.3066406250000000e+01,
+    ),
+];
+
+const GAMMA_DELTA_RATIO_TABLE: [(f64, f64, f64); 14] = [
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.5000000000000000e+00,
+        5.0000000000000000e-01,
+        1.5045055561273502e+00,
+    ),
+    (
+        1.0000000000000001e-01,
+        3.0000000000000000e+00,
+        2.3100000000000001e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        -5.5000000000000000e+00,
+        3.2053925255091077e-05,
+    ),
+    (
+        1.0000000000000000e+03,
+        1.0000000000000000e-10,
+        1.0000000006907255e+00,
+    ),
+    (
+        1.0000000000000000e+06,
+        1.0000000000000000e-08,
+        1.0000001381551100e+00,
+    ),
+    (
+        1.0000000000000000e+10,
+        1.0000000000000000e-03,
+        1.0232929922807030e+00,
+    ),
+    (
+        1.0000000000000000e+15,
+        5.0000000000000000e-01,
+        3.1622776601683788e+07,
+    ),
+    (
+        5.0000000000000000e+00,
+        1.0000000000000001e-15,
+        1.0000000000000016e+00,
+    ),
+    (
+        1.0000000000000000e+20,
+        2.0000000000000000e+00,
+        1.0000000000000000e+40,
+    ),
+    (
+        1.5000000000000000e+02,
+        6.0000000000000000e+01,
+        1.3230040337733908e+135,
+    ),
+    (
+        3.0000000000000000e+02,
+        -1.2000000000000000e+02,
+        1.0939995179569536e-285,
+    ),
+    (
+        -2.5000000000000000e+00,
+        2.5000000000000000e-01,
+        1.8436462374301157e+00,
+    ),
+    (
+        5.0000000000000000e-01,
+        -1.0000000000000000e+00,
+        -2.0000000000000000e+00,
+    ),
+];
@@ -1,20 +1,46 @@
 use approx::assert_relative_eq;
 use proptest::prelude::*;
-use puruspe::{gamma, gammp, gammq, invgammp, ln_gamma};
+use puruspe::{
+    barnes_g, gamma, gammp, gammq, invgammp, ln_barnes_g, ln_gamma, ln_gamma_sign, multigamma,
+    multigammaln, rgamma,
+};
 
 unsafe extern "C" {
     fn tgamma(x: f64) -> f64;
     fn lgamma(x: f64) -> f64;
+    fn lgamma_r(x: f64, sign: *mut i32) -> f64;
 }
 
 #[test]
 fn test_ln_gamma() {
     for (x, y) in LN_GAMMA_TABLE {
         let result = ln_gamma(x);
-        let abs_eps = 1e-10;
-        let rel_eps = 1e-8;
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-14;
         assert_relative_eq!(result, y, epsilon = abs_eps, max_relative = rel_eps);
     }
+    assert_eq!(ln_gamma(f64::INFINITY), f64::INFINITY);
+}
+
+#[test]
+fn test_ln_gamma_sign() {
+    // Γ(1/2 - n) = (-4)^n n! √π / (2n)!
+    for n in 1..150 {
+        let x = 0.5 - n as f64;
+        let expected = n as f64 * 4f64.ln() + 0.5 * std::f64::consts::PI.ln()
+            - (n + 1..=2 * n).map(|k| (k as f64).ln()).sum::<f64>();
+        let expected_sign = if n % 2 == 1 { -1f64 } else { 1f64 };
+        let (result, sign) = ln_gamma_sign(x);
+        assert_relative_eq!(result, expected, epsilon = 1e-13, max_relative = 1e-13);
+        assert_eq!(sign, expected_sign);
+        assert_eq!(ln_gamma(x), result);
+    }
+
+    assert_eq!(ln_gamma_sign(0f64), (f64::INFINITY, 1f64));
+    assert_eq!(ln_gamma_sign(-0f64), (f64::INFINITY, -1f64));
+    let (result, sign) = ln_gamma_sign(-3f64);
+    assert_eq!(result, f64::INFINITY);
+    assert!(sign.is_nan());
 }
 
 #[test]
@@ -22,17 +48,139 @@ fn test_gamma() {
     for (x, y) in GAMMA_TABLE {
         let result = gamma(x);
         let abs_eps = f64::EPSILON;
-        let rel_eps = 1e-10;
+        let rel_eps = 1e-14;
         assert_relative_eq!(result, y, epsilon = abs_eps, max_relative = rel_eps);
     }
 }
 
+#[test]
+fn test_rgamma() {
+    // Exact zeros at the poles of gamma and at infinity
+    for x in [0f64, -1f64, -2f64, -50f64, -1e10] {
+        assert_eq!(rgamma(x), 0f64);
+    }
+    assert_eq!(rgamma(f64::INFINITY), 0f64);
+
+    // Values from mpmath
+    let table = [
+        (-2.9999999999, -6.000000495688555e-10),
+        (-1e-300, -1e-300),
+        (-20.000000001, -2432902216.824495),
+        (-0.5, -0.28209479177387814),
+        (1e-20, 1e-20),
+        (3.5, 0.30090111122547003),
+        (30.25, 4.847767229035297e-32),
+        (100.5, 1.0728505078643488e-157),
+        (170.5, 1.797884546798036e-306),
+        (171.0, 1.3779009677917706e-307),
+        (180.0, 0.0),
+    ];
+    for &(x, expected) in table.iter() {
+        let result = rgamma(x);
+        assert_relative_eq!(result, expected, epsilon = 0f64, max_relative = 1e-14);
+    }
+
+    // Subnormal results, correctly rounded by mpmath, to within two units of 5e-324
+    for &(x, expected) in [(175.5, 1.1773056e-317), (177.0, 5.054e-321)].iter() {
+        let result = rgamma(x);
+        assert_relative_eq!(result, expected, epsilon = 1e-323, max_relative = 0f64);
+    }
+    assert_eq!(rgamma(-180.5), f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_multigamma() {
+    // Γ_1(a) = Γ(a)
+    for &(x, y) in GAMMA_TABLE.iter().filter(|&&(x, _)| x > 0f64) {
+        assert_relative_eq!(
+            multigamma(x, 1),
+            y,
+            epsilon = f64::EPSILON,
+            max_relative = 1e-14
+        );
+        assert_relative_eq!(multigammaln(x, 1), ln_gamma(x), max_relative = 1e-14);
+    }
+
+    // Values from mpmath
+    let table = [
+        (2.5, 2, 0.8570478133976193, 2.356194490192345),
+        (3.0, 3, 2.694924879806965, 14.804406601634039),
+        (10.0, 5, 58.893841851237404, 3.7780746438430024e25),
+        (0.6, 2, 3.223311452728141, 25.11113699545877),
+        (1.2, 3, 3.4166518077332357, 30.46723389855681),
+        (1.00001, 2, 1.1447044789214744, 3.1415128363856306),
+        (4.5, 9, 27.481020344029094, 860707312045.2969),
+        (30.0, 8, 539.313335573383, 1.662668451750791e234),
+    ];
+    for &(a, p, ln_expected, expected) in table.iter() {
+        assert_relative_eq!(multigammaln(a, p), ln_expected, max_relative = 1e-14);
+        assert_relative_eq!(multigamma(a, p), expected, max_relative = 1e-13);
+    }
+
+    // The logarithm stays finite beyond the overflow of Γ_p(a)
+    let table = [
+        (50.0, 10, 1384.3566741679515),
+        (200.0, 4, 3419.2902139313164),
+        (1000.0, 30, 175904.33732084953),
+        (24.75, 50, 1766.5285735736143),
+    ];
+    for &(a, p, ln_expected) in table.iter() {
+        assert_relative_eq!(multigammaln(a, p), ln_expected, max_relative = 1e-14);
+        assert_eq!(multigamma(a, p), f64::INFINITY);
+    }
+}
+
+#[test]
+fn test_barnes_g() {
+    // G(n) = 0! 1! ⋯ (n-2)!
+    let mut expected = 1f64;
+    for n in 2..20 {
+        assert_relative_eq!(barnes_g(n as f64), expected, max_relative = 1e-14);
+        expected *= gamma(n as f64);
+    }
+
+    // Zeros at the non-positive integers
+    for x in [0f64, -1f64, -2f64, -30f64] {
+        assert_eq!(barnes_g(x), 0f64);
+        assert_eq!(ln_barnes_g(x), f64::NEG_INFINITY);
+    }
+
+    // Values from mpmath
+    let table = [
+        (0.5, 0.6032442812094462, -0.5054330544896953),
+        (1.5, 1.069222649266413, 0.06693188843500471),
+        (2.5, 0.9475739010838258, -0.05385034920024052),
+        (1e-8, 1.0000000099615419e-8, -18.420680733990825),
+        (4.25, 2.788261903596119, 1.0254184280831162),
+        (10.5, 2.2987188888494956e18, 42.27888363679505),
+        (25.0, 1.8919846882741926e219, 504.90376174341463),
+        (-0.5, -0.17017206989656153, -1.7709451779743408),
+        (-2.3, 0.03871715878402737, -3.251472397777785),
+        (-7.75, 542668064.5923209, 20.112008391885407),
+        (-20.1, -6.744069909712241e136, 315.06023623404485),
+    ];
+    for &(x, expected, ln_expected) in table.iter() {
+        assert_relative_eq!(barnes_g(x), expected, max_relative = 1e-14);
+        assert_relative_eq!(ln_barnes_g(x), ln_expected, max_relative = 1e-14);
+    }
+
+    // The logarithm stays finite beyond the overflow of G(x)
+    let table = [
+        (100.0, 15258.061392148826),
+        (10000.0, 385444107.33369523),
+        (-150.5, 40367.889511736386),
+    ];
+    for &(x, ln_expected) in table.iter() {
+        assert_relative_eq!(ln_barnes_g(x), ln_expected, max_relative = 1e-14);
+    }
+}
+
 #[test]
 fn test_gammp() {
     for &(a, x, expected) in GAMMP_TABLE.iter() {
         let result = gammp(a, x);
         let abs_eps = f64::EPSILON;
-        let rel_eps = 1e-10;
+        let rel_eps = 1e-13;
         assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
     }
 }
@@ -42,7 +190,7 @@ fn test_gammq() {
     for &(a, x, expected) in GAMMQ_TABLE.iter() {
         let result = gammq(a, x);
         let abs_eps = f64::EPSILON;
-        let rel_eps = 1e-9;
+        let rel_eps = 1e-13;
         assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
     }
 }
@@ -52,7 +200,7 @@ fn test_invgammp() {
     for &(a, p, expected) in INVGAMMP_TABLE.iter() {
         let result = invgammp(p, a);
         let abs_eps = f64::EPSILON;
-        let rel_eps = 1e-9;
+        let rel_eps = 1e-13;
         assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
     }
 }
@@ -63,7 +211,16 @@ proptest! {
         let result = gamma(x);
         let expected = unsafe { tgamma(x) };
         let abs_eps = f64::EPSILON;
-        let rel_eps = 1e-10;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+
+    #[test]
+    fn test_rgamma_proptest(x in -170.0f64..170.0) {
+        let result = rgamma(x);
+        let expected = 1f64 / unsafe { tgamma(x) };
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
         assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
     }
 
@@ -71,9 +228,20 @@ proptest! {
     fn test_ln_gamma_proptest(x in 0.00001f64..100000.0) {
         let result = ln_gamma(x);
         let expected = unsafe { lgamma(x) };
-        let abs_eps = 1e-10;
-        let rel_eps = 1e-8;
+        let abs_eps = 1e-15;
+        let rel_eps = 1e-14;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+
+    #[test]
+    fn test_ln_gamma_sign_proptest(x in -1000.0f64..0.0) {
+        let (result, sign) = ln_gamma_sign(x);
+        let mut expected_sign = 0i32;
+        let expected = unsafe { lgamma_r(x, &mut expected_sign) };
+        let abs_eps = 1e-13;
+        let rel_eps = 1e-13;
         assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+        assert_eq!(sign, expected_sign as f64);
     }
 }
 
@@ -0,0 +1,708 @@
+use approx::assert_relative_eq;
+use puruspe::{
+    beta, beta_lower, beta_upper, betai, betai_derivative_a, betai_derivative_b, betaic,
+    invbetai_a, invbetai_b, invbetai_info,
+};
+
+#[test]
+fn test_betaic() {
+    for &(a, b, x, expected) in BETAIC_TABLE.iter() {
+        let result = betaic(a, b, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-12;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_beta_lower() {
+    for &(a, b, x, expected) in BETA_LOWER_TABLE.iter() {
+        let result = beta_lower(a, b, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_beta_upper() {
+    for &(a, b, x, expected) in BETA_UPPER_TABLE.iter() {
+        let result = beta_upper(a, b, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-12;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_incomplete_beta_limits() {
+    for &(a, b) in [(0.5, 0.5), (2.0, 3.0), (4000.0, 5000.0)].iter() {
+        assert_eq!(betaic(a, b, 0f64), 1f64);
+        assert_eq!(betaic(a, b, 1f64), 0f64);
+        assert_eq!(beta_lower(a, b, 0f64), 0f64);
+        assert_eq!(beta_upper(a, b, 1f64), 0f64);
+    }
+    for &(a, b, x) in [(0.5, 0.5, 0.3), (2.0, 3.0, 0.9), (20.0, 5.0, 0.1)].iter() {
+        assert_relative_eq!(betai(a, b, x) + betaic(a, b, x), 1f64, max_relative = 1e-15);
+        assert_relative_eq!(
+            beta_lower(a, b, x) + beta_upper(a, b, x),
+            beta(a, b),
+            max_relative = 1e-14
+        );
+    }
+}
+
+#[test]
+fn test_incomplete_beta_tail_near_one() {
+    // The lower tail is within 1e-10 of 1 here, so 1 - betai keeps only a few digits
+    for &(a, b, x, expected) in [
+        (1e-11, 0.0921, 0.01, 1.5311731697493023e-10),
+        (3e-12, 0.527, 0.00102, 2.4439066404830815e-11),
+        (2.21e-12, 2.52, 0.0112, 7.113345050615039e-12),
+    ]
+    .iter()
+    {
+        assert_relative_eq!(
+            betaic(a, b, x),
+            expected,
+            epsilon = 0f64,
+            max_relative = 1e-13
+        );
+    }
+    // Likewise for the lower tail with tiny b
+    for &(a, b, x, expected) in [
+        (0.0921, 1e-11, 0.99, 1.531173169749302e-10),
+        (0.0226, 1e-200, 0.9, 4.641088154625159e-199),
+    ]
+    .iter()
+    {
+        assert_relative_eq!(
+            betai(a, b, x),
+            expected,
+            epsilon = 0f64,
+            max_relative = 1e-13
+        );
+    }
+}
+
+#[test]
+fn test_betai_derivative_a() {
+    for &(a, b, x, expected) in BETAI_DERIVATIVE_A_TABLE.iter() {
+        let result = betai_derivative_a(a, b, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    assert_eq!(betai_derivative_a(2f64, 3f64, 0f64), 0f64);
+    assert_eq!(betai_derivative_a(2f64, 3f64, 1f64), 0f64);
+}
+
+#[test]
+fn test_betai_derivative_b() {
+    for &(a, b, x, expected) in BETAI_DERIVATIVE_B_TABLE.iter() {
+        let result = betai_derivative_b(a, b, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    assert_eq!(betai_derivative_b(2f64, 3f64, 0f64), 0f64);
+    assert_eq!(betai_derivative_b(2f64, 3f64, 1f64), 0f64);
+    // Out of reach of the continued fraction, but it must still return
+    betai_derivative_b(1e300, 1f64, 0.5);
+}
+
+#[test]
+fn test_invbetai_a() {
+    for &(p, b, x, expected) in INVBETAI_A_TABLE.iter() {
+        let result = invbetai_a(p, b, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    assert_eq!(invbetai_a(0f64, 2f64, 0.5), f64::INFINITY);
+    assert_eq!(invbetai_a(1f64, 2f64, 0.5), 0f64);
+}
+
+#[test]
+fn test_invbetai_b() {
+    for &(p, a, x, expected) in INVBETAI_B_TABLE.iter() {
+        let result = invbetai_b(p, a, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    assert_eq!(invbetai_b(0f64, 2f64, 0.5), 0f64);
+    assert_eq!(invbetai_b(1f64, 2f64, 0.5), f64::INFINITY);
+}
+
+#[test]
+fn test_invbetai_shape_far_tail() {
+    // Values from mpmath; p is far below the resolution of I_x(a,b) - p
+    for &(p, b, x, expected) in [
+        (1e-300, 169.415, 0.9, 11157.650778623844),
+        (1e-250, 2.0, 0.5, 839.1983258905107),
+    ]
+    .iter()
+    {
+        assert_relative_eq!(invbetai_a(p, b, x), expected, max_relative = 1e-13);
+    }
+    for &(p, a, x, expected) in [
+        (4.641088154625159e-199, 0.0226, 0.9, 1.0000000000000001e-200),
+        (1e-150, 0.5, 0.3, 8.12846876254341e-151),
+        (1e-300, 5.0, 0.9, 1.6933077799587826e-300),
+    ]
+    .iter()
+    {
+        assert_relative_eq!(
+            invbetai_b(p, a, x),
+            expected,
+            epsilon = 0f64,
+            max_relative = 1e-13
+        );
+    }
+}
+
+#[test]
+fn test_invbetai_info() {
+    for &(a, b, p, expected) in INVBETAI_INFO_TABLE.iter() {
+        let (result, residual, iterations) = invbetai_info(p, a, b);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+        assert!(residual.abs() <= 1e-14 * p.min(1f64 - p));
+        assert!(iterations <= 30);
+    }
+    assert_eq!(invbetai_info(0f64, 2f64, 3f64), (0f64, 0f64, 0));
+    assert_eq!(invbetai_info(1f64, 2f64, 3f64), (1f64, 0f64, 0));
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Tables from scripts/incomplete_beta_table.py
+// └─────────────────────────────────────────────────────────┘
+const BETAIC_TABLE: [(f64, f64, f64, f64); 13] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        7.9516723530086653e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        4.0000000000000002e-01,
+        4.7519999999999996e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        5.0000000000000000e-01,
+        9.0000000000000002e-01,
+        6.8335708497998771e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e+01,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+    ),
+    (
+        1.0000000000000001e-01,
+        1.0000000000000001e-01,
+        9.8999999999999999e-01,
+        3.2030825037562638e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        1.0000000000000000e-10,
+        1.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        5.0000000000000000e+01,
+        6.9999999999999996e-01,
+        7.1789798769185792e-27,
+    ),
+    (
+        2.0000000000000000e+01,
+        5.0000000000000000e+00,
+        9.9900000000000000e-01,
+        4.1836185909793870e-11,
+    ),
+    (
+        5.0000000000000000e-01,
+        3.0000000000000000e+01,
+        9.0000000000000002e-01,
+        1.0793411337245473e-31,
+    ),
+    (
+        1.0000000000000000e-03,
+        2.0000000000000000e+00,
+        2.9999999999999999e-01,
+        5.0409059367542458e-04,
+    ),
+    (
+        3.0000000000000000e+00,
+        1.0000000000000000e+04,
+        1.0000000000000000e-03,
+        2.7557999713298522e-03,
+    ),
+    (
+        4.0000000000000000e+03,
+        5.0000000000000000e+03,
+        5.0000000000000000e-01,
+        2.4781874589710982e-26,
+    ),
+    (
+        4.0000000000000000e+03,
+        5.0000000000000000e+03,
+        4.6999999999999997e-01,
+        5.7433494721664877e-07,
+    ),
+];
+
+const BETA_LOWER_TABLE: [(f64, f64, f64, f64); 11] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        6.4350110879328437e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        4.0000000000000002e-01,
+        4.3733333333333339e-02,
+    ),
+    (
+        5.0000000000000000e+00,
+        5.0000000000000000e-01,
+        9.0000000000000002e-01,
+        2.5733519442896241e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e+01,
+        5.0000000000000000e-01,
+        5.4125441122345144e-07,
+    ),
+    (
+        1.0000000000000001e-01,
+        1.0000000000000001e-01,
+        9.8999999999999999e-01,
+        1.3399877807526272e+01,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        1.0000000000000000e-10,
+        4.9999999993333338e-21,
+    ),
+    (
+        1.0000000000000000e+00,
+        5.0000000000000000e+01,
+        6.9999999999999996e-01,
+        2.0000000000000000e-02,
+    ),
+    (
+        2.0000000000000000e+01,
+        5.0000000000000000e+00,
+        9.9900000000000000e-01,
+        4.7054394878513262e-06,
+    ),
+    (
+        5.0000000000000000e-01,
+        3.0000000000000000e+01,
+        9.0000000000000002e-01,
+        3.2495542039483022e-01,
+    ),
+    (
+        1.0000000000000000e-03,
+        2.0000000000000000e+00,
+        2.9999999999999999e-01,
+        9.9849741199433026e+02,
+    ),
+    (
+        3.0000000000000000e+00,
+        1.0000000000000000e+04,
+        1.0000000000000000e-03,
+        1.9938901931216000e-12,
+    ),
+];
+
+const BETA_UPPER_TABLE: [(f64, f64, f64, f64); 11] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        2.4980915447965089e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        4.0000000000000002e-01,
+        3.9599999999999996e-02,
+    ),
+    (
+        5.0000000000000000e+00,
+        5.0000000000000000e-01,
+        9.0000000000000002e-01,
+        5.5536321826945034e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e+01,
+        5.0000000000000000e-01,
+        5.4125441122345144e-07,
+    ),
+    (
+        1.0000000000000001e-01,
+        1.0000000000000001e-01,
+        9.8999999999999999e-01,
+        6.3147616815238896e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        1.0000000000000000e-10,
+        8.3333333333333329e-02,
+    ),
+    (
+        1.0000000000000000e+00,
+        5.0000000000000000e+01,
+        6.9999999999999996e-01,
+        1.4357959753837159e-28,
+    ),
+    (
+        2.0000000000000000e+01,
+        5.0000000000000000e+00,
+        9.9900000000000000e-01,
+        1.9685764120926910e-16,
+    ),
+    (
+        5.0000000000000000e-01,
+        3.0000000000000000e+01,
+        9.0000000000000002e-01,
+        3.5073775185889296e-32,
+    ),
+    (
+        1.0000000000000000e-03,
+        2.0000000000000000e+00,
+        2.9999999999999999e-01,
+        5.0358700666875578e-01,
+    ),
+    (
+        3.0000000000000000e+00,
+        1.0000000000000000e+04,
+        1.0000000000000000e-03,
+        5.5099468484062455e-15,
+    ),
+];
+
+const BETAI_DERIVATIVE_A_TABLE: [(f64, f64, f64, f64); 11] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        -5.9262002214337939e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        4.0000000000000002e-01,
+        -2.4086937608755657e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        5.0000000000000000e-01,
+        9.0000000000000002e-01,
+        -5.0778814053345708e-02,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e+01,
+        5.0000000000000000e-01,
+        -9.1092957195236482e-02,
+    ),
+    (
+        1.0000000000000001e-01,
+        1.0000000000000001e-01,
+        9.8999999999999999e-01,
+        -1.6443984910968290e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        1.0000000000000000e-10,
+        -1.3465510556182206e-18,
+    ),
+    (
+        1.0000000000000000e+00,
+        5.0000000000000000e+01,
+        6.9999999999999996e-01,
+        -2.9798980553013350e-26,
+    ),
+    (
+        2.0000000000000000e+01,
+        5.0000000000000000e+00,
+        9.9900000000000000e-01,
+        -9.5129298381212900e-12,
+    ),
+    (
+        5.0000000000000000e-01,
+        3.0000000000000000e+01,
+        9.0000000000000002e-01,
+        -5.6805282565981958e-31,
+    ),
+    (
+        1.0000000000000000e-03,
+        2.0000000000000000e+00,
+        2.9999999999999999e-01,
+        -5.0420816678413038e-01,
+    ),
+    (
+        1.5000000000000000e+02,
+        2.0000000000000000e+02,
+        4.5000000000000001e-01,
+        -1.7371316066678112e-02,
+    ),
+];
+
+const BETAI_DERIVATIVE_B_TABLE: [(f64, f64, f64, f64); 11] = [
+    (
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        2.7681552435835127e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        4.0000000000000002e-01,
+        1.5634433641359877e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        5.0000000000000000e-01,
+        9.0000000000000002e-01,
+        6.2162644996780214e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e+01,
+        5.0000000000000000e-01,
+        9.1092957195236482e-02,
+    ),
+    (
+        1.0000000000000001e-01,
+        1.0000000000000001e-01,
+        9.8999999999999999e-01,
+        3.0310719231211030e+00,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        1.0000000000000000e-10,
+        3.4999999991333334e-20,
+    ),
+    (
+        1.0000000000000000e+00,
+        5.0000000000000000e+01,
+        6.9999999999999996e-01,
+        8.6432965346131233e-27,
+    ),
+    (
+        2.0000000000000000e+01,
+        5.0000000000000000e+00,
+        9.9900000000000000e-01,
+        2.2657054151471282e-10,
+    ),
+    (
+        5.0000000000000000e-01,
+        3.0000000000000000e+01,
+        9.0000000000000002e-01,
+        2.5030520607677264e-31,
+    ),
+    (
+        1.0000000000000000e-03,
+        2.0000000000000000e+00,
+        2.9999999999999999e-01,
+        3.6894935554668767e-04,
+    ),
+    (
+        1.5000000000000000e+02,
+        2.0000000000000000e+02,
+        4.5000000000000001e-01,
+        1.3604270242588222e-02,
+    ),
+];
+
+const INVBETAI_A_TABLE: [(f64, f64, f64, f64); 9] = [
+    (
+        5.0000000000000000e-01,
+        2.0000000000000000e+00,
+        2.9999999999999999e-01,
+        1.0249306894715169e+00,
+    ),
+    (
+        1.0000000000000001e-01,
+        5.0000000000000000e+00,
+        5.0000000000000000e-01,
+        9.7330749273428978e+00,
+    ),
+    (
+        9.0000000000000002e-01,
+        1.0000000000000000e+00,
+        2.0000000000000001e-01,
+        6.5464169101422442e-02,
+    ),
+    (
+        2.5000000000000001e-02,
+        1.0000000000000000e+01,
+        5.9999999999999998e-01,
+        2.9259251861862733e+01,
+    ),
+    (
+        2.9999999999999999e-01,
+        5.0000000000000000e-01,
+        5.0000000000000003e-02,
+        2.9973535857937078e-01,
+    ),
+    (
+        1.0000000000000000e-10,
+        3.0000000000000000e+00,
+        1.0000000000000001e-01,
+        1.1866369813460576e+01,
+    ),
+    (
+        1.0000000000000001e-30,
+        2.0000000000000000e+01,
+        6.9999999999999996e-01,
+        3.3016416037919447e+02,
+    ),
+    (
+        9.9900000000000000e-01,
+        5.0000000000000000e+01,
+        9.0000000000000002e-01,
+        2.6990780490152525e+02,
+    ),
+    (
+        5.9999999999999998e-01,
+        2.0000000000000000e+02,
+        4.0000000000000002e-01,
+        1.2969676189419877e+02,
+    ),
+];
+
+const INVBETAI_B_TABLE: [(f64, f64, f64, f64); 9] = [
+    (
+        5.0000000000000000e-01,
+        2.0000000000000000e+00,
+        2.9999999999999999e-01,
+        4.2467021757181005e+00,
+    ),
+    (
+        1.0000000000000001e-01,
+        5.0000000000000000e+00,
+        5.0000000000000000e-01,
+        1.9048000167078829e+00,
+    ),
+    (
+        9.0000000000000002e-01,
+        1.0000000000000000e+00,
+        2.0000000000000001e-01,
+        1.0318851158516170e+01,
+    ),
+    (
+        2.5000000000000001e-02,
+        1.0000000000000000e+01,
+        5.9999999999999998e-01,
+        1.8514275692265545e+00,
+    ),
+    (
+        2.9999999999999999e-01,
+        5.0000000000000000e-01,
+        5.0000000000000003e-02,
+        1.6755832945588454e+00,
+    ),
+    (
+        1.0000000000000000e-10,
+        3.0000000000000000e+00,
+        1.0000000000000001e-01,
+        2.7738035339785203e-07,
+    ),
+    (
+        1.0000000000000001e-30,
+        2.0000000000000000e+01,
+        6.9999999999999996e-01,
+        8.2885768516379746e-27,
+    ),
+    (
+        9.9900000000000000e-01,
+        5.0000000000000000e+01,
+        9.0000000000000002e-01,
+        1.5336967534904927e+01,
+    ),
+    (
+        5.9999999999999998e-01,
+        2.0000000000000000e+02,
+        4.0000000000000002e-01,
+        3.0680746009194741e+02,
+    ),
+];
+
+const INVBETAI_INFO_TABLE: [(f64, f64, f64, f64); 9] = [
+    (
+        1.0000000000000000e-02,
+        1.0000000000000000e-02,
+        2.9999999999999999e-01,
+        6.4281199357186129e-23,
+    ),
+    (
+        1.0000000000000000e-02,
+        1.0000000000000000e-02,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+    ),
+    (
+        2.0000000000000000e-02,
+        5.0000000000000000e-01,
+        2.9999999999999999e-01,
+        2.7812492151413758e-26,
+    ),
+    (
+        5.0000000000000000e-01,
+        2.0000000000000000e-02,
+        1.0000000000000000e-02,
+        6.3234855528142550e-02,
+    ),
+    (
+        1.0000000000000001e-01,
+        1.0000000000000000e+01,
+        9.9900000000000000e-01,
+        2.9642086824739644e-01,
+    ),
+    (
+        1.0000000000000000e+02,
+        1.0000000000000000e+00,
+        9.9999999999999995e-21,
+        6.3095734448019325e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        3.0000000000000000e+00,
+        1.0000000000000000e-100,
+        4.0824829046386300e-51,
+    ),
+    (
+        5.0000000000000000e+01,
+        5.0000000000000000e-01,
+        5.0000000000000000e-01,
+        9.9543827752689618e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        5.0000000000000000e+00,
+        9.9999990000000005e-01,
+        9.8471102183056936e-01,
+    ),
+];
@@ -0,0 +1,985 @@
+use approx::assert_relative_eq;
+use puruspe::{
+    gamma, gamma_lower, gamma_lower_scaled, gamma_upper, gamma_upper_scaled, gammp_derivative_a,
+    gammp_derivative_x, gammp_interval, gammq, gammq_derivative_a, gammq_derivative_x, invgammp_a,
+    invgammq, invgammq_a, ln_gammp, ln_gammq,
+};
+
+#[test]
+fn test_gamma_lower() {
+    for &(a, x, expected) in GAMMA_LOWER_TABLE.iter() {
+        let result = gamma_lower(a, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_gamma_upper() {
+    for &(a, x, expected) in GAMMA_UPPER_TABLE.iter() {
+        let result = gamma_upper(a, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_gamma_lower_scaled() {
+    for &(a, x, expected) in GAMMA_LOWER_SCALED_TABLE.iter() {
+        let result = gamma_lower_scaled(a, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_gamma_upper_scaled() {
+    for &(a, x, expected) in GAMMA_UPPER_SCALED_TABLE.iter() {
+        let result = gamma_upper_scaled(a, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_gamma_upper_subnormal_x() {
+    // The series for small a must terminate once its terms underflow
+    assert_relative_eq!(
+        gamma_upper(0.3, 1e-320),
+        2.991568987687591,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        gamma_upper(0f64, 1e-320),
+        736.2500252260724,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        gamma_upper(-0.3, 1e-310),
+        3.33333333333331e+93,
+        max_relative = 1e-13
+    );
+    assert_relative_eq!(
+        gamma_upper_scaled(-0.3, 1e-320),
+        3.3333333333333335,
+        max_relative = 1e-13
+    );
+}
+
+#[test]
+fn test_incomplete_gamma_limits() {
+    assert_eq!(gamma_lower(2.5, 0f64), 0f64);
+    assert_eq!(gamma_upper(2.5, 0f64), gamma(2.5));
+    assert_eq!(gamma_upper(-1.5, 0f64), f64::INFINITY);
+    assert_eq!(gamma_upper_scaled(-2f64, 0f64), 0.5);
+    assert!(gamma_lower(-2f64, 1f64).is_nan());
+    assert!(gamma_lower_scaled(0f64, 1f64).is_nan());
+    // The scaled functions stay finite where the unscaled ones underflow
+    assert_eq!(gamma_upper(3f64, 1e3), 0f64);
+    // e^x x^{-3} Γ(3,x) = 1/x + 2/x^2 + 2/x^3
+    assert_relative_eq!(
+        gamma_upper_scaled(3f64, 1e3),
+        1.002002e-3,
+        max_relative = 1e-14
+    );
+}
+
+#[test]
+fn test_invgammq() {
+    for &(q, a, expected) in INVGAMMQ_TABLE.iter() {
+        let result = invgammq(q, a);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    assert_eq!(invgammq(1f64, 2f64), 0f64);
+    assert_eq!(invgammq(0f64, 2f64), f64::INFINITY);
+}
+
+#[test]
+fn test_invgammp_a() {
+    for &(p, x, expected) in INVGAMMP_A_TABLE.iter() {
+        let result = invgammp_a(p, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    assert_eq!(invgammp_a(0f64, 2f64), f64::INFINITY);
+    assert_eq!(invgammp_a(1f64, 2f64), 0f64);
+}
+
+#[test]
+fn test_invgammq_a() {
+    for &(q, x, expected) in INVGAMMQ_A_TABLE.iter() {
+        let result = invgammq_a(q, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    assert_eq!(invgammq_a(0f64, 2f64), 0f64);
+    assert_eq!(invgammq_a(1f64, 2f64), f64::INFINITY);
+}
+
+#[test]
+fn test_gammp_derivative_x() {
+    for &(a, x, expected) in GAMMP_DERIVATIVE_X_TABLE.iter() {
+        let result = gammp_derivative_x(a, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+        assert_eq!(gammq_derivative_x(a, x), -result);
+    }
+    assert_eq!(gammp_derivative_x(1f64, 0f64), 1f64);
+    assert_eq!(gammp_derivative_x(2f64, 0f64), 0f64);
+}
+
+#[test]
+fn test_gammp_derivative_a() {
+    for &(a, x, expected) in GAMMP_DERIVATIVE_A_TABLE.iter() {
+        let result = gammp_derivative_a(a, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+        assert_eq!(gammq_derivative_a(a, x), -result);
+    }
+    assert_eq!(gammp_derivative_a(1f64, f64::INFINITY), 0f64);
+    assert_eq!(gammq_derivative_a(1f64, f64::INFINITY), 0f64);
+    // Out of reach of the continued fraction, but it must still return
+    gammp_derivative_a(1e300, 1e300);
+}
+
+#[test]
+fn test_ln_gammp() {
+    for &(a, x, expected) in LN_GAMMP_TABLE.iter() {
+        let result = ln_gammp(a, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    assert_eq!(ln_gammp(2f64, 0f64), f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_ln_gammq() {
+    for &(a, x, expected) in LN_GAMMQ_TABLE.iter() {
+        let result = ln_gammq(a, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    assert_eq!(ln_gammq(2f64, 0f64), 0f64);
+}
+
+#[test]
+fn test_gammp_interval() {
+    for &(a, x0, x1, expected) in GAMMP_INTERVAL_TABLE.iter() {
+        let result = gammp_interval(a, x0, x1);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+    assert_eq!(gammp_interval(2f64, 1f64, 1f64), 0f64);
+}
+
+#[test]
+fn test_gammq_far_tail() {
+    // The quadrature used for large a must not lose the sign of an underflowed tail
+    assert_eq!(gammq(250f64, 2f64), 1f64);
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Tables from scripts/incomplete_gamma_table.py
+// └─────────────────────────────────────────────────────────┘
+const GAMMA_LOWER_TABLE: [(f64, f64, f64); 14] = [
+    (
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        6.1199136611177185e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        6.3212055882855767e-01,
+    ),
+    (
+        2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        9.2227121230783404e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        1.2636724162490678e+00,
+    ),
+    (
+        1.0000000000000000e+01,
+        3.0000000000000000e+01,
+        3.6287741565904691e+05,
+    ),
+    (
+        1.0000000000000000e-10,
+        5.0000000000000000e-01,
+        9.9999999988630104e+09,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.5000000000000000e+00,
+        2.8655956232034390e+00,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.4000000000000000e+02,
+        7.9813545271472892e+259,
+    ),
+    (
+        2.0000000000000000e+01,
+        1.0000000000000001e-05,
+        4.9999523811796613e-102,
+    ),
+    (
+        -5.0000000000000000e-01,
+        2.0000000000000001e-01,
+        -5.3379001739104579e+00,
+    ),
+    (
+        -2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        -9.4583815331344290e-01,
+    ),
+    (
+        -1.0000000000000001e-09,
+        6.9999999999999996e-01,
+        -1.0000000009509845e+09,
+    ),
+    (
+        -3.0500000000000000e+01,
+        8.0000000000000004e-01,
+        -1.2954180442686038e+01,
+    ),
+    (
+        -2.0000000999999998e+00,
+        9.0000000000000002e-01,
+        -4.9999997019796800e+06,
+    ),
+];
+
+const GAMMA_UPPER_TABLE: [(f64, f64, f64); 21] = [
+    (
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        1.1604624847937441e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        3.6787944117144233e-01,
+    ),
+    (
+        2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        4.0706917587130298e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        2.2736327583750931e+01,
+    ),
+    (
+        1.0000000000000000e+01,
+        3.0000000000000000e+01,
+        2.5843409530985166e+00,
+    ),
+    (
+        1.0000000000000000e-10,
+        5.0000000000000000e-01,
+        5.5977359477318778e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.5000000000000000e+00,
+        1.2597336448415158e-01,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.4000000000000000e+02,
+        3.0107871849158409e+260,
+    ),
+    (
+        2.0000000000000000e+01,
+        1.0000000000000001e-05,
+        1.2164510040883200e+17,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e-08,
+        1.7843465089050831e+01,
+    ),
+    (
+        0.0000000000000000e+00,
+        5.0000000000000000e-01,
+        5.5977359477616084e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        5.0000000000000000e+00,
+        1.1482955912753257e-03,
+    ),
+    (
+        0.0000000000000000e+00,
+        5.0000000000000000e+01,
+        3.7832640295504591e-24,
+    ),
+    (
+        -5.0000000000000000e-01,
+        2.0000000000000001e-01,
+        1.7929924720994257e+00,
+    ),
+    (
+        -1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.4849550677592205e-01,
+    ),
+    (
+        -2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        5.2943283050101001e-04,
+    ),
+    (
+        -3.0000000000000000e+00,
+        1.0000000000000000e-03,
+        3.3283383197264618e+08,
+    ),
+    (
+        -1.0000000000000001e-09,
+        6.9999999999999996e-01,
+        3.7376884316456543e-01,
+    ),
+    (
+        -3.0500000000000000e+01,
+        8.0000000000000004e-01,
+        1.2954180442686038e+01,
+    ),
+    (
+        -1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        7.9769440774540270e-36,
+    ),
+    (
+        -2.0000000999999998e+00,
+        9.0000000000000002e-01,
+        1.5518885953966322e-01,
+    ),
+];
+
+const GAMMA_LOWER_SCALED_TABLE: [(f64, f64, f64); 14] = [
+    (
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        2.1388224963904761e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.7182818284590453e+00,
+    ),
+    (
+        2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        1.1883352001667480e+00,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        2.9179207419798769e-01,
+    ),
+    (
+        1.0000000000000000e+01,
+        3.0000000000000000e+01,
+        6.5672243029512638e+03,
+    ),
+    (
+        1.0000000000000000e-10,
+        5.0000000000000000e-01,
+        1.6487212706269508e+10,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.5000000000000000e+00,
+        1.1371800977459756e+01,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.4000000000000000e+02,
+        6.0827324877886862e-02,
+    ),
+    (
+        2.0000000000000000e+01,
+        1.0000000000000001e-05,
+        5.0000023809534631e-02,
+    ),
+    (
+        -5.0000000000000000e-01,
+        2.0000000000000001e-01,
+        -2.9157101039869873e+00,
+    ),
+    (
+        -2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        -2.9614432258881402e+02,
+    ),
+    (
+        -1.0000000000000001e-09,
+        6.9999999999999996e-01,
+        -2.0137527086672688e+09,
+    ),
+    (
+        -3.0500000000000000e+01,
+        8.0000000000000004e-01,
+        -3.1922002770755922e-02,
+    ),
+    (
+        -2.0000000999999998e+00,
+        9.0000000000000002e-01,
+        -9.9613919014924262e+06,
+    ),
+];
+
+const GAMMA_UPPER_SCALED_TABLE: [(f64, f64, f64); 21] = [
+    (
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        4.0556507920419635e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+    ),
+    (
+        2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        5.2450366457852515e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        5.2500000000000000e+00,
+    ),
+    (
+        1.0000000000000000e+01,
+        3.0000000000000000e+01,
+        4.6770468433470505e-02,
+    ),
+    (
+        1.0000000000000000e-10,
+        5.0000000000000000e-01,
+        9.2291063254280004e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.5000000000000000e+00,
+        4.9991143822774731e-01,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.4000000000000000e+02,
+        2.2945745563881353e-01,
+    ),
+    (
+        2.0000000000000000e+01,
+        1.0000000000000001e-05,
+        1.2164631686591817e+117,
+    ),
+    (
+        0.0000000000000000e+00,
+        1.0000000000000000e-08,
+        1.7843465267485485e+01,
+    ),
+    (
+        0.0000000000000000e+00,
+        5.0000000000000000e-01,
+        9.2291063248373051e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        5.0000000000000000e+00,
+        1.7042217628473219e-01,
+    ),
+    (
+        0.0000000000000000e+00,
+        5.0000000000000000e+01,
+        1.9615109930114869e-02,
+    ),
+    (
+        -5.0000000000000000e-01,
+        2.0000000000000001e-01,
+        9.7938254687199733e-01,
+    ),
+    (
+        -1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        4.0365263767680593e-01,
+    ),
+    (
+        -2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        1.6576676083084754e-01,
+    ),
+    (
+        -3.0000000000000000e+00,
+        1.0000000000000000e-03,
+        3.3316683227702099e-01,
+    ),
+    (
+        -1.0000000000000001e-09,
+        6.9999999999999996e-01,
+        7.5267801962229008e-01,
+    ),
+    (
+        -3.0500000000000000e+01,
+        8.0000000000000004e-01,
+        3.1922002770755922e-02,
+    ),
+    (
+        -1.0000000000000000e+01,
+        4.0000000000000000e+01,
+        1.9688640848493307e-02,
+    ),
+    (
+        -2.0000000999999998e+00,
+        9.0000000000000002e-01,
+        3.0917942815239979e-01,
+    ),
+];
+const INVGAMMQ_TABLE: [(f64, f64, f64); 10] = [
+    (
+        5.0000000000000000e-01,
+        1.0000000000000000e+00,
+        6.9314718055994529e-01,
+    ),
+    (
+        9.0000000000000002e-01,
+        2.5000000000000000e+00,
+        8.0515399348116146e-01,
+    ),
+    (
+        1.0000000000000001e-01,
+        5.0000000000000000e-01,
+        1.3527717270477073e+00,
+    ),
+    (
+        1.0000000000000000e-02,
+        1.0000000000000000e+01,
+        1.8783117393312526e+01,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.5000000000000000e+02,
+        1.5617301345523109e+02,
+    ),
+    (
+        1.0000000000000000e-10,
+        1.0000000000000001e-01,
+        1.8119637782112438e+01,
+    ),
+    (
+        9.9999999999999995e-21,
+        3.0000000000000000e+00,
+        5.3349774071025479e+01,
+    ),
+    (
+        1.0000000000000000e-100,
+        5.0000000000000000e+01,
+        3.7643878236368590e+02,
+    ),
+    (
+        1.0000000000000000e-300,
+        5.0000000000000000e-01,
+        6.8693631561119707e+02,
+    ),
+    (
+        1.0000000000000000e-300,
+        1.0000000000000000e+03,
+        2.6667520733019951e+03,
+    ),
+];
+
+const INVGAMMP_A_TABLE: [(f64, f64, f64); 9] = [
+    (
+        5.0000000000000000e-01,
+        1.0000000000000000e+00,
+        1.3142500103453505e+00,
+    ),
+    (
+        1.0000000000000001e-01,
+        3.0000000000000000e+00,
+        5.7918177056206668e+00,
+    ),
+    (
+        9.7499999999999998e-01,
+        1.0000000000000000e+01,
+        4.8336161215620770e+00,
+    ),
+    (
+        2.5000000000000001e-02,
+        1.0000000000000000e+01,
+        1.7127158816966190e+01,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e-02,
+        2.8382325538382075e-01,
+    ),
+    (
+        9.9999999999999995e-21,
+        5.0000000000000000e+00,
+        3.7695652039780740e+01,
+    ),
+    (
+        1.0000000000000000e-10,
+        5.0000000000000000e+01,
+        1.0162620444466140e+02,
+    ),
+    (
+        9.9990000000000001e-01,
+        2.0000000000000000e+00,
+        2.0384737949439421e-03,
+    ),
+    (
+        5.9999999999999998e-01,
+        5.0000000000000000e+02,
+        4.9467930341252185e+02,
+    ),
+];
+const INVGAMMQ_A_TABLE: [(f64, f64, f64); 9] = [
+    (
+        5.0000000000000000e-01,
+        1.0000000000000000e+00,
+        1.3142500103453505e+00,
+    ),
+    (
+        1.0000000000000001e-01,
+        3.0000000000000000e+00,
+        1.4206976115576928e+00,
+    ),
+    (
+        9.7499999999999998e-01,
+        1.0000000000000000e+01,
+        1.7127158816966187e+01,
+    ),
+    (
+        2.5000000000000001e-02,
+        1.0000000000000000e+01,
+        4.8336161215620761e+00,
+    ),
+    (
+        2.9999999999999999e-01,
+        1.0000000000000000e-02,
+        8.6872537550302317e-02,
+    ),
+    (
+        9.9999999999999995e-21,
+        5.0000000000000000e+00,
+        8.7085590818072810e-18,
+    ),
+    (
+        1.0000000000000000e-10,
+        5.0000000000000000e+01,
+        1.2824028937739756e+01,
+    ),
+    (
+        9.9990000000000001e-01,
+        2.0000000000000000e+00,
+        9.5376522463634217e+00,
+    ),
+    (
+        5.9999999999999998e-01,
+        5.0000000000000000e+02,
+        5.0600868142832030e+02,
+    ),
+];
+
+const GAMMP_DERIVATIVE_X_TABLE: [(f64, f64, f64); 10] = [
+    (
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        1.6143422587153617e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        3.6787944117144233e-01,
+    ),
+    (
+        2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        1.9460869331856584e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        9.0223522157741792e-02,
+    ),
+    (
+        1.0000000000000000e+01,
+        3.0000000000000000e+01,
+        5.0756749585450051e-06,
+    ),
+    (
+        1.0000000000000000e-03,
+        5.0000000000000000e-01,
+        1.2129196984382424e-03,
+    ),
+    (
+        2.9999999999999999e-01,
+        9.9999999999999995e-07,
+        5.2978608017787883e+03,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.4000000000000000e+02,
+        2.4606378364525405e-02,
+    ),
+    (
+        1.5000000000000000e+02,
+        2.0000000000000000e+02,
+        2.5928107665078455e-05,
+    ),
+    (
+        2.0000000000000000e+01,
+        1.0000000000000000e-03,
+        8.2124187203255689e-75,
+    ),
+];
+
+const GAMMP_DERIVATIVE_A_TABLE: [(f64, f64, f64); 10] = [
+    (
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        -8.2293609697959880e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -4.3172971063489868e-01,
+    ),
+    (
+        2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        -2.2754855127826082e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        -5.6913250644571048e-02,
+    ),
+    (
+        1.0000000000000000e+01,
+        3.0000000000000000e+01,
+        -8.5002029891204346e-06,
+    ),
+    (
+        1.0000000000000000e-03,
+        5.0000000000000000e-01,
+        -5.6035940010800311e-01,
+    ),
+    (
+        2.9999999999999999e-01,
+        9.9999999999999995e-07,
+        -2.4098786936949879e-01,
+    ),
+    (
+        1.5000000000000000e+02,
+        1.4000000000000000e+02,
+        -2.3793726651202104e-02,
+    ),
+    (
+        1.5000000000000000e+02,
+        2.0000000000000000e+02,
+        -2.9869292120415200e-05,
+    ),
+    (
+        2.0000000000000000e+01,
+        1.0000000000000000e-03,
+        -4.0769543987981728e-78,
+    ),
+];
+
+const LN_GAMMP_TABLE: [(f64, f64, f64); 10] = [
+    (
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        -1.0634020471545287e+00,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -4.5867514538708187e-01,
+    ),
+    (
+        2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        -3.6559881263791899e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        -2.9440317325720811e+00,
+    ),
+    (
+        1.0000000000000000e+01,
+        3.0000000000000000e+01,
+        -7.1217762226036572e-06,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.0000000000000000e+03,
+        -0.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e+03,
+        1.0000000000000000e+05,
+        -0.0000000000000000e+00,
+    ),
+    (
+        5.0000000000000000e-01,
+        1.0000000000000000e-300,
+        -3.4526698171147160e+02,
+    ),
+    (
+        2.0000000000000000e+02,
+        1.0000000000000000e+00,
+        -8.6422699977464458e+02,
+    ),
+    (
+        1.0000000000000000e-03,
+        5.0000000000000000e-01,
+        -5.6022355238477669e-04,
+    ),
+];
+
+const LN_GAMMQ_TABLE: [(f64, f64, f64); 10] = [
+    (
+        5.0000000000000000e-01,
+        1.0000000000000001e-01,
+        -4.2354632347596571e-01,
+    ),
+    (
+        1.0000000000000000e+00,
+        1.0000000000000000e+00,
+        -1.0000000000000000e+00,
+    ),
+    (
+        2.5000000000000000e+00,
+        3.0000000000000000e+00,
+        -1.1834550131691672e+00,
+    ),
+    (
+        5.0000000000000000e+00,
+        2.0000000000000000e+00,
+        -5.4089850944686692e-02,
+    ),
+    (
+        1.0000000000000000e+01,
+        3.0000000000000000e+01,
+        -1.1852356955071915e+01,
+    ),
+    (
+        2.0000000000000000e+00,
+        1.0000000000000000e+03,
+        -9.9309124522068475e+02,
+    ),
+    (
+        1.0000000000000000e+03,
+        1.0000000000000000e+05,
+        -9.4403797843570952e+04,
+    ),
+    (
+        5.0000000000000000e-01,
+        1.0000000000000000e-300,
+        -1.1283791670955126e-150,
+    ),
+    (
+        2.0000000000000000e+02,
+        1.0000000000000000e+00,
+        -0.0000000000000000e+00,
+    ),
+    (
+        1.0000000000000000e-03,
+        5.0000000000000000e-01,
+        -7.4874547519064638e+00,
+    ),
+];
+
+const GAMMP_INTERVAL_TABLE: [(f64, f64, f64, f64); 11] = [
+    (
+        2.0000000000000000e+00,
+        1.0000000000000000e+00,
+        3.0000000000000000e+00,
+        5.3661060887142886e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        0.0000000000000000e+00,
+        2.0000000000000001e-01,
+        4.7291074313446191e-01,
+    ),
+    (
+        5.0000000000000000e+00,
+        3.0000000000000000e+00,
+        7.0000000000000000e+00,
+        6.4227163664170073e-01,
+    ),
+    (
+        2.0000000000000000e+00,
+        4.0000000000000000e+01,
+        4.1000000000000000e+01,
+        1.0854147251488562e-16,
+    ),
+    (
+        1.0000000000000000e+01,
+        1.0000000000000000e+02,
+        2.0000000000000000e+02,
+        1.1253473960842733e-31,
+    ),
+    (
+        5.0000000000000000e+00,
+        5.0000000000000000e+00,
+        5.0000000010000001e+00,
+        1.7546738426853923e-10,
+    ),
+    (
+        1.0000000000000000e+04,
+        1.0000000000000000e+04,
+        1.0001000000000000e+04,
+        3.9891236222633682e-03,
+    ),
+    (
+        3.0000000000000000e+00,
+        1.0000000000000001e-05,
+        1.1000000000000000e-05,
+        5.5166086544719150e-17,
+    ),
+    (
+        1.0000000000000000e-02,
+        1.0000000000000001e-30,
+        1.0000000000000000e+00,
+        4.9373649251677654e-01,
+    ),
+    (
+        5.0000000000000000e-01,
+        1.0000000000000000e-300,
+        9.9999999999999999e-300,
+        2.4398690652100297e-150,
+    ),
+    (
+        8.0000000000000000e+00,
+        3.0000000000000000e+00,
+        2.0000000000000000e+00,
+        -1.0807784888498687e-02,
+    ),
+];
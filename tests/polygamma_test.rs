@@ -0,0 +1,97 @@
+use approx::assert_relative_eq;
+use puruspe::{digamma, polygamma, trigamma};
+
+#[test]
+fn test_digamma() {
+    for &(x, expected) in DIGAMMA_TABLE.iter() {
+        let result = digamma(x);
+        let abs_eps = 1e-15;
+        let rel_eps = 1e-13;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_digamma_poles() {
+    for x in [0f64, -1f64, -2f64, -100f64] {
+        assert!(digamma(x).is_nan());
+    }
+}
+
+#[test]
+fn test_polygamma() {
+    for &(n, x, expected) in POLYGAMMA_TABLE.iter() {
+        let result = polygamma(n, x);
+        let abs_eps = f64::EPSILON;
+        let rel_eps = 1e-12;
+        assert_relative_eq!(result, expected, epsilon = abs_eps, max_relative = rel_eps);
+    }
+}
+
+#[test]
+fn test_trigamma() {
+    for &(n, x, expected) in POLYGAMMA_TABLE.iter().filter(|(n, _, _)| *n == 1) {
+        assert_eq!(trigamma(x), polygamma(n, x));
+        assert_relative_eq!(trigamma(x), expected, max_relative = 1e-12);
+    }
+    assert_eq!(trigamma(-3f64), f64::INFINITY);
+}
+
+// ┌─────────────────────────────────────────────────────────┐
+//  Tables from scripts/polygamma_table.py
+// └─────────────────────────────────────────────────────────┘
+const DIGAMMA_TABLE: [(f64, f64); 20] = [
+    (1.0000000000000000e-10, -1.0000000000577215e+10),
+    (1.0000000000000001e-05, -1.0000057719921567e+05),
+    (1.0000000000000001e-01, -1.0423754940411076e+01),
+    (5.0000000000000000e-01, -1.9635100260214235e+00),
+    (1.0000000000000000e+00, -5.7721566490153287e-01),
+    (1.4616321449683622e+00, -9.2412655217294273e-17),
+    (1.5000000000000000e+00, 3.6489973978576520e-02),
+    (2.0000000000000000e+00, 4.2278433509846713e-01),
+    (3.5000000000000000e+00, 1.1031566406452431e+00),
+    (7.0000000000000000e+00, 1.8727843350984672e+00),
+    (1.0000000000000000e+01, 2.2517525890667209e+00),
+    (5.0000000000000000e+01, 3.9019896734278920e+00),
+    (1.0000000000000000e+05, 1.1512920464961896e+01),
+    (1.0000000000000000e+10, 2.3025850929890456e+01),
+    (1.0000000000000000e+100, 2.3025850929940458e+02),
+    (-5.0000000000000000e-01, 3.6489973978576520e-02),
+    (-1.5000000000000000e+00, 7.0315664064524319e-01),
+    (-2.2500000000000000e+00, 4.1585835646579721e+00),
+    (-1.0100000000000000e+01, 1.2030052248548309e+01),
+    (-1.0090000000000001e+02, -5.0497508469714454e+00),
+];
+
+const POLYGAMMA_TABLE: [(usize, f64, f64); 30] = [
+    (1, 1.0000000000000001e-05, 1.0000000001644909e+10),
+    (1, 5.0000000000000000e-01, 4.9348022005446790e+00),
+    (1, 1.0000000000000000e+00, 1.6449340668482264e+00),
+    (1, 3.5000000000000000e+00, 3.3035775610023488e-01),
+    (1, 3.0000000000000000e+01, 3.3895060357739946e-02),
+    (1, 1.0000000000000000e+08, 1.0000000049999999e-08),
+    (2, 1.0000000000000001e-01, -2.0018614573783436e+03),
+    (2, 1.0000000000000000e+00, -2.4041138063191885e+00),
+    (2, 1.0000000000000000e+01, -1.1049834970802067e-02),
+    (2, 1.0000000000000000e+03, -1.0010004999998333e-06),
+    (3, 2.5000000000000000e-01, 1.5387821440091884e+03),
+    (3, 2.0000000000000000e+00, 4.9393940226682914e-01),
+    (3, 1.0000000000000000e+02, 2.0301999900013330e-06),
+    (5, 5.0000000000000000e-01, 7.6911135486024359e+03),
+    (5, 7.5000000000000000e+00, 1.3927076560043099e-03),
+    (10, 1.0000000000000000e+00, -3.6305933116066288e+06),
+    (10, 2.5000000000000000e+01, -4.6216645150488321e-09),
+    (30, 3.0000000000000000e+00, -4.2949627387982982e+17),
+    (100, 5.0000000000000000e-01, -2.3661006604908971e+188),
+    (200, 1.0000000000000000e+01, -7.8865787114015539e+173),
+    (1, -5.0000000000000000e-01, 8.9348022005446790e+00),
+    (1, -2.7500000000000000e+00, 1.9433868949488463e+01),
+    (2, -5.0000000000000000e-01, -8.2879664423431998e-01),
+    (2, -3.2999999999999998e+00, 6.8769445477485235e+01),
+    (3, -1.2500000000000000e+00, 1.5582209125348506e+03),
+    (4, -1.0000000000000001e-01, 2.3999582275538268e+06),
+    (4, -2.0500000000000000e+01, -3.0793205973402757e-05),
+    (6, -5.5000000000000000e+00, -2.4537507916641975e-03),
+    (10, -1.5000000000000000e+00, -1.5622959659323300e+02),
+    (10, -5.0000000000000000e-01, -4.2108858768975493e+04),
+];